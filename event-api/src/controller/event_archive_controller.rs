@@ -0,0 +1,45 @@
+use crate::service::event_archive_service;
+use actix_web::web::Query;
+use actix_web::{error::ErrorBadRequest, get, HttpResponse, Responder};
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+pub struct GetEventArchiveStatsQuery {
+    location_key: Option<String>,
+    from_date: String,
+    to_date: String,
+}
+
+#[get("/get_event_archive_stats")]
+pub async fn get_event_archive_stats(query: Query<GetEventArchiveStatsQuery>) -> impl Responder {
+    let query = query.into_inner();
+    let response = event_archive_service::get_event_archive_stats(
+        query.location_key,
+        query.from_date,
+        query.to_date,
+    )
+    .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[get("/get_event_archive_site_trend")]
+pub async fn get_event_archive_site_trend(
+    query: Query<GetEventArchiveStatsQuery>,
+) -> impl Responder {
+    let query = query.into_inner();
+    let response = event_archive_service::get_event_archive_site_trend(
+        query.location_key,
+        query.from_date,
+        query.to_date,
+    )
+    .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
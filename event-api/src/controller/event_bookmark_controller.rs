@@ -0,0 +1,63 @@
+use crate::model::api::event_bookmark_request::{EventBookmarkDeleteRequest, EventBookmarkRequest};
+use crate::service::auth::account_user_service;
+use crate::service::event_bookmark_service;
+use actix_web::web::{Json, Query};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+pub struct GetBookmarkListQuery {
+    #[serde(default)]
+    going_only: bool,
+}
+
+#[get("/get_bookmark_list")]
+pub async fn get_bookmark_list(
+    req: HttpRequest,
+    query: Query<GetBookmarkListQuery>,
+) -> impl Responder {
+    let account_id = match account_user_service::get_account_id_from_authorization_header(&req) {
+        Some(id) => id,
+        None => return ErrorUnauthorized("Unauthorized").into(),
+    };
+    let response = event_bookmark_service::get_bookmark_list(account_id, query.going_only).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/save_bookmark")]
+pub async fn save_bookmark(req: HttpRequest, body: Json<EventBookmarkRequest>) -> impl Responder {
+    let account_id = match account_user_service::get_account_id_from_authorization_header(&req) {
+        Some(id) => id,
+        None => return ErrorUnauthorized("Unauthorized").into(),
+    };
+    let response = event_bookmark_service::save_bookmark(account_id, body.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/delete_bookmark")]
+pub async fn delete_bookmark(
+    req: HttpRequest,
+    body: Json<EventBookmarkDeleteRequest>,
+) -> impl Responder {
+    let account_id = match account_user_service::get_account_id_from_authorization_header(&req) {
+        Some(id) => id,
+        None => return ErrorUnauthorized("Unauthorized").into(),
+    };
+    let response = event_bookmark_service::delete_bookmark(account_id, body.into_inner()).await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -0,0 +1,73 @@
+use crate::model::api::event_recommend_request::EventClickRequest;
+use crate::service::auth::account_user_service;
+use crate::service::event_recommend_service;
+use actix_web::web::{Json, Query};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+use serde::Deserialize;
+
+fn default_limit() -> usize {
+    10
+}
+
+#[derive(Clone, Deserialize)]
+pub struct GetSimilarEventListQuery {
+    site_id: String,
+    site_event_id: String,
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[derive(Clone, Deserialize)]
+pub struct GetRecommendEventListQuery {
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[get("/get_similar_event_list")]
+pub async fn get_similar_event_list(query: Query<GetSimilarEventListQuery>) -> impl Responder {
+    let response = event_recommend_service::get_similar_events(
+        query.site_id.clone(),
+        query.site_event_id.clone(),
+        query.limit,
+    )
+    .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[get("/get_recommend_event_list")]
+pub async fn get_recommend_event_list(
+    req: HttpRequest,
+    query: Query<GetRecommendEventListQuery>,
+) -> impl Responder {
+    let account_id = match account_user_service::get_account_id_from_authorization_header(&req) {
+        Some(id) => id,
+        None => return ErrorUnauthorized("Unauthorized").into(),
+    };
+    let response = event_recommend_service::get_recommend_events(account_id, query.limit).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/add_event_click")]
+pub async fn add_event_click(req: HttpRequest, body: Json<EventClickRequest>) -> impl Responder {
+    let account_id = match account_user_service::get_account_id_from_authorization_header(&req) {
+        Some(id) => id,
+        None => return ErrorUnauthorized("Unauthorized").into(),
+    };
+    let response = event_recommend_service::add_event_click(account_id, body.into_inner()).await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -0,0 +1,22 @@
+use crate::service::event_stream_service::EventStreamBroadcaster;
+use actix_web::http::header;
+use actix_web::web::{Data, Query};
+use actix_web::{get, HttpResponse, Responder};
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize)]
+pub struct StreamEventInfoQuery {
+    location_key: String,
+}
+
+#[get("/stream_event_info")]
+pub async fn stream_event_info(
+    broadcaster: Data<EventStreamBroadcaster>,
+    query: Query<StreamEventInfoQuery>,
+) -> impl Responder {
+    let stream = broadcaster.subscribe_sse(query.into_inner().location_key);
+    return HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(stream);
+}
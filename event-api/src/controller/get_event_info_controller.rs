@@ -1,21 +1,28 @@
+use crate::service::get_event_service::get_event_calendar;
 use crate::service::get_event_service::get_event_info_list;
 use crate::service::get_event_service::get_event_info_master;
 use actix_web::web::Query;
-use actix_web::{error::ErrorInternalServerError, get, HttpResponse, Responder};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError},
+    get, HttpResponse, Responder,
+};
 use serde::Deserialize;
 
 #[get("/get_event_info_master")]
 pub async fn get_event_master() -> impl Responder {
     let response = get_event_info_master();
-    return HttpResponse::Ok()
-        .content_type("application/json")
-        .json(response);
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
 }
 
 #[derive(Clone, Deserialize)]
 pub struct GetEventListQuery {
     location_key: String,
     event_date: String,
+    #[serde(default)]
+    include_flagged: bool,
 }
 
 #[get("/get_event_list")]
@@ -26,6 +33,7 @@ pub async fn get_event_list(query: Query<GetEventListQuery>) -> impl Responder {
     let response = get_event_info_list(
         (location_key.clone()).to_string(),
         (event_date.clone()).to_string(),
+        query.include_flagged,
     )
     .await;
     return match response {
@@ -33,3 +41,20 @@ pub async fn get_event_list(query: Query<GetEventListQuery>) -> impl Responder {
         Err(e) => ErrorInternalServerError(e.to_string()).into(),
     };
 }
+
+#[derive(Clone, Deserialize)]
+pub struct GetEventCalendarQuery {
+    location_key: String,
+    month: String,
+}
+
+#[get("/get_event_calendar")]
+pub async fn get_event_calendar_summary(query: Query<GetEventCalendarQuery>) -> impl Responder {
+    let query = query.into_inner();
+
+    let response = get_event_calendar(query.location_key, query.month).await;
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
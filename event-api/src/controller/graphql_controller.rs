@@ -0,0 +1,42 @@
+use crate::graphql_object::event_role::AdminContext;
+use crate::graphql_object::event_schema::EventSchema;
+use crate::util::admin_util;
+use actix_web::web::Data;
+use actix_web::{get, post, HttpRequest, HttpResponse, Responder};
+use async_graphql_actix_web::{GraphQLBatchRequest, GraphQLRequest, GraphQLResponse};
+
+// POSTは単体・バッチのどちらのリクエストも受け付ける
+#[post("/graphql")]
+pub async fn graphql(
+    schema: Data<EventSchema>,
+    req: HttpRequest,
+    body: GraphQLBatchRequest,
+) -> GraphQLResponse {
+    let mut request = body.into_inner();
+    // 管理者キーが正しければcontextにセット
+    if admin_util::is_admin_request(&req) {
+        request = request.data(AdminContext);
+    }
+    return schema.execute_batch(request).await.into();
+}
+
+// GETはクエリ文字列で指定
+#[get("/graphql")]
+pub async fn graphql_get(
+    schema: Data<EventSchema>,
+    req: HttpRequest,
+    query: GraphQLRequest,
+) -> GraphQLResponse {
+    let mut request = query.into_inner();
+    if admin_util::is_admin_request(&req) {
+        request = request.data(AdminContext);
+    }
+    return schema.execute(request).await.into();
+}
+
+#[get("/graphql/sdl")]
+pub async fn graphql_sdl(schema: Data<EventSchema>) -> impl Responder {
+    return HttpResponse::Ok()
+        .content_type("text/plain; charset=utf-8")
+        .body(schema.sdl());
+}
@@ -0,0 +1,54 @@
+use crate::model::api::manual_event_request::{ManualEventModerateRequest, ManualEventRequest};
+use crate::service::auth::account_user_service;
+use crate::service::manual_event_service;
+use crate::util::admin_util;
+use actix_web::web::Json;
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+
+#[post("/add_manual_event")]
+pub async fn add_manual_event(req: HttpRequest, body: Json<ManualEventRequest>) -> impl Responder {
+    // ログインユーザーか管理者のみ登録可能
+    if account_user_service::get_account_id_from_authorization_header(&req).is_none()
+        && !admin_util::is_admin_request(&req)
+    {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = manual_event_service::add_manual_event(body.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[get("/get_pending_manual_event_list")]
+pub async fn get_pending_manual_event_list(req: HttpRequest) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = manual_event_service::get_pending_manual_events().await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/moderate_manual_event")]
+pub async fn moderate_manual_event(
+    req: HttpRequest,
+    body: Json<ManualEventModerateRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = manual_event_service::moderate_manual_event(body.into_inner()).await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -0,0 +1,34 @@
+use crate::model::api::spam_rule_request::SpamRuleRequest;
+use crate::service::spam_filter_service;
+use crate::util::admin_util;
+use actix_web::web::Json;
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+
+#[get("/get_spam_rule")]
+pub async fn get_spam_rule(req: HttpRequest) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = spam_filter_service::get_spam_rule();
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/update_spam_rule")]
+pub async fn update_spam_rule(req: HttpRequest, body: Json<SpamRuleRequest>) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = spam_filter_service::update_spam_rule(body.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
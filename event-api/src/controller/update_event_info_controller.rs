@@ -1,9 +1,11 @@
+use crate::service::event_stream_service::EventStreamBroadcaster;
 use crate::service::update_event_service::update_event_execute;
+use actix_web::web::Data;
 use actix_web::{error::ErrorInternalServerError, post, HttpResponse, Responder};
 
 #[post("/update_event_info")]
-pub async fn update_event_info() -> impl Responder {
-    let response = update_event_execute().await;
+pub async fn update_event_info(broadcaster: Data<EventStreamBroadcaster>) -> impl Responder {
+    let response = update_event_execute(&broadcaster).await;
 
     return match response {
         Ok(_r) => HttpResponse::Ok().json(""),
@@ -1,93 +1,129 @@
+use crate::model::api::manual_event_request::ManualEventRequest;
 use crate::model::db::event_collection::EventCollection;
 use crate::model::db::event_info_collection::EventSearchMasterCollection;
+use crate::service::event_stream_service::EventStreamBroadcaster;
 use crate::util::date_util;
-use chrono::{DateTime, Datelike};
+use crate::util::url_util;
+use chrono::{DateTime as ChronoDateTime, Datelike};
 use chrono_tz::Tz;
+use futures::stream::FuturesUnordered;
+use mongodb::bson::DateTime;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use std::error::Error;
+use std::future::Future;
 use std::{thread, time};
 
-pub async fn get_event_data(
+// 手動登録で取得するページの上限
+const MANUAL_EVENT_TIMEOUT_SECS: u64 = 10;
+const MANUAL_EVENT_MAX_BODY_SIZE: u64 = 2 * 1024 * 1024;
+
+// 各サイトの収集を並行で行い、終わったサイトから結果を返す（無効なサイトは収集しない）
+pub fn get_event_data(
     event_search_master: EventSearchMasterCollection,
-    event_date: String,
-    update_time: i64,
+    event_date: DateTime,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
+) -> FuturesUnordered<impl Future<Output = Result<Vec<EventCollection>, Box<dyn Error>>> + '_> {
+    let event_date_time = date_util::from_bson_date_time(event_date);
+    let location_key = event_search_master._id.clone();
+    return event_search_master
+        .get_enabled_sites()
+        .into_iter()
+        .filter_map(|s| {
+            let search_key = s.search_key.clone()?;
+            Some(gather_site(
+                s.site_id.clone(),
+                location_key.clone(),
+                search_key,
+                event_date,
+                event_date_time,
+                update_time,
+                broadcaster,
+            ))
+        })
+        .collect();
+}
+
+// サイトごとの収集
+async fn gather_site(
+    site_id: String,
+    location_key: String,
+    search_key: String,
+    event_date: DateTime,
+    event_date_time: ChronoDateTime<Tz>,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
-    let event_date_time = date_util::parse_str_jst_date(event_date.clone())?;
-    // 各サイトの収集を並行で行う
-    let future_tunagate = tunagate_gather(
-        event_search_master._id.clone(),
-        event_search_master.tunagate_key,
-        event_date.clone(),
-        update_time,
-    );
-    let future_jmty = jmty_gather(
-        event_search_master._id.clone(),
-        event_search_master.jmty_key,
-        event_date.clone(),
-        event_date_time,
-        update_time,
-    );
-    let future_koryupa = koryupa_gather(
-        event_search_master._id.clone(),
-        event_search_master.koryupa_key,
-        event_date.clone(),
-        event_date_time,
-        update_time,
-    );
-    let future_kokuchpro = kokuchpro_gather(
-        event_search_master._id.clone(),
-        event_search_master.kokuchpro_key,
-        event_date.clone(),
-        event_date_time,
-        update_time,
-    );
-    let future_twipla = twipla_gather(
-        event_search_master._id.clone(),
-        event_search_master.twipla_key,
-        event_date.clone(),
-        event_date_time,
-        update_time,
-    );
-    // 結果取得
-    let (result_tunagate, result_jmty, result_koryupa, result_kokuchpro, result_twipla) = futures::join!(
-        future_tunagate,
-        future_jmty,
-        future_koryupa,
-        future_kokuchpro,
-        future_twipla
-    );
-    let (
-        mut events_tunagate,
-        mut events_jmty,
-        mut events_koryupa,
-        mut events_kokuchpro,
-        mut events_twipla,
-    ) = (
-        result_tunagate?,
-        result_jmty?,
-        result_koryupa?,
-        result_kokuchpro?,
-        result_twipla?,
-    );
-    let mut result_vec: Vec<EventCollection> = Vec::new();
-    result_vec.append(&mut events_tunagate);
-    result_vec.append(&mut events_jmty);
-    result_vec.append(&mut events_koryupa);
-    result_vec.append(&mut events_kokuchpro);
-    result_vec.append(&mut events_twipla);
-    return Ok(result_vec);
+    return match site_id.as_str() {
+        "tunagate" => {
+            tunagate_gather(
+                location_key,
+                search_key,
+                event_date,
+                update_time,
+                broadcaster,
+            )
+            .await
+        }
+        "jmty" => {
+            jmty_gather(
+                location_key,
+                search_key,
+                event_date,
+                event_date_time,
+                update_time,
+                broadcaster,
+            )
+            .await
+        }
+        "koryupa" => {
+            koryupa_gather(
+                location_key,
+                search_key,
+                event_date,
+                event_date_time,
+                update_time,
+                broadcaster,
+            )
+            .await
+        }
+        "kokuchpro" => {
+            kokuchpro_gather(
+                location_key,
+                search_key,
+                event_date,
+                event_date_time,
+                update_time,
+                broadcaster,
+            )
+            .await
+        }
+        "twipla" => {
+            twipla_gather(
+                location_key,
+                search_key,
+                event_date,
+                event_date_time,
+                update_time,
+                broadcaster,
+            )
+            .await
+        }
+        _ => Ok(Vec::new()),
+    };
 }
 
 async fn tunagate_gather(
     location_key: String,
     search_key: String,
-    event_date: String,
-    update_time: i64,
+    event_date: DateTime,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
     let mut result_vec: Vec<EventCollection> = Vec::new();
     macro_rules! base_url {() => ("https://tunagate.com/api/circle/search?pref_key={search_key}&event_date={event_date}&page={page}")}
     let mut page = 1;
-    let event_date_ref = &event_date;
+    let event_date_ref = &date_util::format_bson_date(event_date, "%Y-%m-%d");
 
     loop {
         let url = format!(
@@ -101,7 +137,7 @@ async fn tunagate_gather(
         let gather_events = EventCollection::from_tunagate_json(
             json_str,
             location_key.clone(),
-            event_date_ref.clone(),
+            event_date,
             update_time,
         )?;
         let gather_events_refer = &gather_events;
@@ -118,6 +154,7 @@ async fn tunagate_gather(
             for add_event in add_events_filtered {
                 result_vec.push(add_event.clone());
             }
+            broadcaster.send_progress(&location_key, format!("tunagate page {} done", page));
             thread::sleep(time::Duration::from_millis(500));
             page = page + 1
         }
@@ -128,9 +165,10 @@ async fn tunagate_gather(
 async fn jmty_gather(
     location_key: String,
     search_key: String,
-    event_date: String,
-    event_date_time: DateTime<Tz>,
-    update_time: i64,
+    event_date: DateTime,
+    event_date_time: ChronoDateTime<Tz>,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
     let mut result_vec: Vec<EventCollection> = Vec::new();
     macro_rules! base_url {
@@ -159,7 +197,7 @@ async fn jmty_gather(
     let mut gather_events = EventCollection::from_jmty_html(
         html_url_jp,
         location_key.clone(),
-        event_date.clone(),
+        event_date,
         update_time,
     )?;
     // 日付をスラッシュ形式で指定
@@ -182,7 +220,7 @@ async fn jmty_gather(
     let mut gather_events_url_slash = EventCollection::from_jmty_html(
         html_url_slash,
         location_key.clone(),
-        event_date.clone(),
+        event_date,
         update_time,
     )?;
     gather_events.append(&mut gather_events_url_slash);
@@ -196,15 +234,17 @@ async fn jmty_gather(
             result_vec.push(gather_event);
         }
     }
+    broadcaster.send_progress(&location_key, "jmty done".to_string());
     return Ok(result_vec);
 }
 
 async fn koryupa_gather(
     location_key: String,
     search_key: String,
-    event_date: String,
-    event_date_time: DateTime<Tz>,
-    update_time: i64,
+    event_date: DateTime,
+    event_date_time: ChronoDateTime<Tz>,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
     let url = format!(
         "https://koryupa.jp/events/get_of_day/ymd:{event_date}/prf1:{search_key}",
@@ -213,21 +253,19 @@ async fn koryupa_gather(
     );
     let resp = reqwest::get(url).await?;
     let html = resp.text().await?;
-    let gather_events = EventCollection::from_koryupa_html(
-        html,
-        location_key.clone(),
-        event_date.clone(),
-        update_time,
-    )?;
+    let gather_events =
+        EventCollection::from_koryupa_html(html, location_key.clone(), event_date, update_time)?;
+    broadcaster.send_progress(&location_key, "koryupa done".to_string());
     return Ok(gather_events);
 }
 
 async fn kokuchpro_gather(
     location_key: String,
     search_key: String,
-    event_date: String,
-    event_date_time: DateTime<Tz>,
-    update_time: i64,
+    event_date: DateTime,
+    event_date_time: ChronoDateTime<Tz>,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
     let mut result_vec: Vec<EventCollection> = Vec::new();
     macro_rules! base_url {
@@ -250,7 +288,7 @@ async fn kokuchpro_gather(
         let result = EventCollection::from_kokuchpro_html(
             html,
             location_key.clone(),
-            event_date.clone(),
+            event_date,
             event_date_time,
             update_time,
         )?;
@@ -266,6 +304,7 @@ async fn kokuchpro_gather(
         for add_event in add_events_filtered {
             result_vec.push(add_event.clone());
         }
+        broadcaster.send_progress(&location_key, format!("kokuchpro page {} done", page));
         thread::sleep(time::Duration::from_millis(500));
         if !next_flag {
             break;
@@ -278,9 +317,10 @@ async fn kokuchpro_gather(
 async fn twipla_gather(
     location_key: String,
     search_key: String,
-    event_date: String,
-    event_date_time: DateTime<Tz>,
-    update_time: i64,
+    event_date: DateTime,
+    event_date_time: ChronoDateTime<Tz>,
+    update_time: DateTime,
+    broadcaster: &EventStreamBroadcaster,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
     let mut result_vec: Vec<EventCollection> = Vec::new();
     macro_rules! base_url {
@@ -295,7 +335,7 @@ async fn twipla_gather(
         let url = format!(
             base_url!(),
             search_key = search_key_encoded,
-            event_date = event_date,
+            event_date = date_util::format_bson_date(event_date, "%Y-%m-%d"),
             page = page
         );
         let resp = reqwest::get(url).await?;
@@ -303,7 +343,7 @@ async fn twipla_gather(
         let result = EventCollection::from_twipla_html(
             html,
             location_key.clone(),
-            event_date.clone(),
+            event_date,
             event_date_time,
             update_time,
         )?;
@@ -319,6 +359,7 @@ async fn twipla_gather(
         for add_event in add_events_filtered {
             result_vec.push(add_event.clone());
         }
+        broadcaster.send_progress(&location_key, format!("twipla page {} done", page));
         thread::sleep(time::Duration::from_millis(500));
         if !next_flag {
             break;
@@ -327,3 +368,31 @@ async fn twipla_gather(
     }
     return Ok(result_vec);
 }
+
+pub async fn get_manual_event_data(
+    request: ManualEventRequest,
+    update_time: DateTime,
+) -> Result<EventCollection, Box<dyn Error>> {
+    // http(s)以外やプライベートアドレスのURLは対象外
+    let (url, host, addr) = url_util::resolve_public_url(&request.url).await?;
+    // 検証したアドレスに固定し、リダイレクトは追わない
+    let client = reqwest::Client::builder()
+        .timeout(time::Duration::from_secs(MANUAL_EVENT_TIMEOUT_SECS))
+        .redirect(reqwest::redirect::Policy::none())
+        .resolve(&host, addr)
+        .build()?;
+    let mut resp = client.get(url).send().await?;
+    if resp.content_length().unwrap_or(0) > MANUAL_EVENT_MAX_BODY_SIZE {
+        return Err("Response is too large".into());
+    }
+    let mut body: Vec<u8> = Vec::new();
+    while let Some(chunk) = resp.chunk().await? {
+        if (body.len() + chunk.len()) as u64 > MANUAL_EVENT_MAX_BODY_SIZE {
+            return Err("Response is too large".into());
+        }
+        body.extend_from_slice(&chunk);
+    }
+    let html = String::from_utf8_lossy(&body).to_string();
+    let event = EventCollection::from_manual_html(html, request, update_time)?;
+    return Ok(event);
+}
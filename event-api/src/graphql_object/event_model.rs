@@ -0,0 +1,185 @@
+use async_graphql::*;
+
+use crate::model::api::event_info_master_response::{
+    EventInfoMasterResponseKV, EventInfoMasterResponseLocation,
+};
+use crate::model::api::event_page_response::EventPageResponse;
+use crate::model::api::event_response::EventResponse;
+use crate::model::api::event_update_status_response::EventUpdateStatusResponse;
+use crate::model::db::spam_rule_collection::SpamRuleCollection;
+use crate::util::date_util;
+
+#[derive(SimpleObject)]
+pub struct Location {
+    pub key: String,
+    pub label: String,
+    pub sites: Vec<String>,
+}
+
+impl From<EventInfoMasterResponseLocation> for Location {
+    fn from(l: EventInfoMasterResponseLocation) -> Self {
+        Location {
+            key: l.key,
+            label: l.label,
+            sites: l.sites,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct Site {
+    pub key: String,
+    pub label: String,
+}
+
+impl From<EventInfoMasterResponseKV> for Site {
+    fn from(s: EventInfoMasterResponseKV) -> Self {
+        Site {
+            key: s.key,
+            label: s.label,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct EventSpam {
+    pub score: i32,
+    pub flagged: bool,
+}
+
+#[derive(SimpleObject)]
+pub struct Event {
+    pub site_id: String,
+    pub site_event_id: String,
+    pub location_key: String,
+    pub title: String,
+    pub url: String,
+    pub event_date: String,
+    pub event_time: Option<String>,
+    pub update_time: i64,
+    pub spam: Option<EventSpam>,
+}
+
+impl From<EventResponse> for Event {
+    fn from(e: EventResponse) -> Self {
+        Event {
+            site_id: e.site_id,
+            site_event_id: e.site_event_id,
+            location_key: e.location_key,
+            title: e.title,
+            url: e.url,
+            event_date: e.event_date,
+            event_time: e.event_time,
+            update_time: e.update_time,
+            spam: e.spam.map(|s| EventSpam {
+                score: s.score,
+                flagged: s.flagged,
+            }),
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct EventPage {
+    pub total_count: usize,
+    pub events: Vec<Event>,
+}
+
+#[derive(SimpleObject)]
+pub struct UpdateStatus {
+    pub event_date: String,
+    pub gathered: bool,
+    // 最終収集日時（JST）
+    pub update_time: Option<String>,
+}
+
+impl From<EventPageResponse> for EventPage {
+    fn from(p: EventPageResponse) -> Self {
+        EventPage {
+            total_count: p.total_count as usize,
+            events: p.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<EventUpdateStatusResponse> for UpdateStatus {
+    fn from(s: EventUpdateStatusResponse) -> Self {
+        UpdateStatus {
+            event_date: s.event_date,
+            gathered: s.gathered,
+            update_time: s.update_time,
+        }
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct SpamRule {
+    pub site_id: String,
+    pub blocklist_keywords: Vec<String>,
+    pub blocklist_title_patterns: Vec<String>,
+    pub keyword_score: i32,
+    pub pattern_score: i32,
+    pub poster_repeat_date_count: i64,
+    pub poster_repeat_score: i32,
+    pub flag_threshold: i32,
+    pub update_time: Option<String>,
+}
+
+impl From<SpamRuleCollection> for SpamRule {
+    fn from(r: SpamRuleCollection) -> Self {
+        SpamRule {
+            site_id: r._id,
+            blocklist_keywords: r.blocklist_keywords,
+            blocklist_title_patterns: r.blocklist_title_patterns,
+            keyword_score: r.keyword_score,
+            pattern_score: r.pattern_score,
+            poster_repeat_date_count: r.poster_repeat_date_count,
+            poster_repeat_score: r.poster_repeat_score,
+            flag_threshold: r.flag_threshold,
+            update_time: r
+                .update_time
+                .map(|t| date_util::format_bson_date(t, "%Y-%m-%d %H:%M:%S")),
+        }
+    }
+}
+
+#[derive(InputObject)]
+pub struct EventFilterInputObject {
+    #[graphql(validator(min_length = 1))]
+    pub location_key: String,
+    // yyyy-mm-dd
+    #[graphql(validator(min_length = 1))]
+    pub event_date: String,
+    pub site_ids: Option<Vec<String>>,
+    pub keyword: Option<String>,
+    #[graphql(default = false)]
+    pub include_flagged: bool,
+}
+
+#[derive(InputObject)]
+pub struct PageInputObject {
+    #[graphql(default = 0, validator(minimum = 0))]
+    pub offset: i32,
+    #[graphql(default = 50, validator(minimum = 1, maximum = 200))]
+    pub limit: i32,
+}
+
+#[derive(InputObject)]
+pub struct ModerateManualEventInputObject {
+    #[graphql(validator(min_length = 1))]
+    pub location_key: String,
+    #[graphql(validator(min_length = 1))]
+    pub site_event_id: String,
+    pub approved: bool,
+}
+
+#[derive(InputObject)]
+pub struct SpamRuleInputObject {
+    pub blocklist_keywords: Vec<String>,
+    pub blocklist_title_patterns: Vec<String>,
+    pub keyword_score: i32,
+    pub pattern_score: i32,
+    pub poster_repeat_date_count: i64,
+    pub poster_repeat_score: i32,
+    pub flag_threshold: i32,
+}
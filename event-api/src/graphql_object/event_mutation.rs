@@ -0,0 +1,48 @@
+use async_graphql::*;
+
+use crate::graphql_object::event_model;
+use crate::graphql_object::event_role::{Role, RoleGuard};
+use crate::model::api::manual_event_request::ManualEventModerateRequest;
+use crate::model::api::spam_rule_request::SpamRuleRequest;
+use crate::service::{manual_event_service, spam_filter_service};
+
+pub struct Mutation;
+
+#[Object]
+impl Mutation {
+    // 手動登録イベントの承認・否認
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn moderate_manual_event(
+        &self,
+        input: event_model::ModerateManualEventInputObject,
+    ) -> Result<bool> {
+        manual_event_service::moderate_manual_event(ManualEventModerateRequest {
+            location_key: input.location_key,
+            site_event_id: input.site_event_id,
+            approved: input.approved,
+        })
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+        return Ok(true);
+    }
+
+    // スパム判定ルールの更新
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn update_spam_rule(
+        &self,
+        input: event_model::SpamRuleInputObject,
+    ) -> Result<event_model::SpamRule> {
+        let rule = spam_filter_service::update_spam_rule(SpamRuleRequest {
+            blocklist_keywords: input.blocklist_keywords,
+            blocklist_title_patterns: input.blocklist_title_patterns,
+            keyword_score: input.keyword_score,
+            pattern_score: input.pattern_score,
+            poster_repeat_date_count: input.poster_repeat_date_count,
+            poster_repeat_score: input.poster_repeat_score,
+            flag_threshold: input.flag_threshold,
+        })
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+        return Ok(rule.into());
+    }
+}
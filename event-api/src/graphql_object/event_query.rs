@@ -0,0 +1,65 @@
+use async_graphql::*;
+
+use crate::graphql_object::event_model;
+use crate::graphql_object::event_role::{Role, RoleGuard};
+use crate::service::{get_event_service, spam_filter_service};
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    // 地域の一覧
+    async fn locations(&self) -> Result<Vec<event_model::Location>> {
+        let master =
+            get_event_service::get_event_info_master().map_err(|e| Error::new(e.to_string()))?;
+        return Ok(master.locations.into_iter().map(Into::into).collect());
+    }
+
+    // 収集対象のサイト一覧
+    async fn sites(&self) -> Result<Vec<event_model::Site>> {
+        let master =
+            get_event_service::get_event_info_master().map_err(|e| Error::new(e.to_string()))?;
+        return Ok(master.sites.into_iter().map(Into::into).collect());
+    }
+
+    // 条件を指定したイベント一覧
+    async fn events(
+        &self,
+        filter: event_model::EventFilterInputObject,
+        page: Option<event_model::PageInputObject>,
+    ) -> Result<event_model::EventPage> {
+        let (offset, limit) = match page {
+            Some(p) => (p.offset as u64, p.limit as i64),
+            None => (0, 50),
+        };
+        let page = get_event_service::get_event_page(
+            filter.location_key,
+            filter.event_date,
+            filter.include_flagged,
+            filter.site_ids,
+            filter.keyword,
+            offset,
+            limit,
+        )
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+        return Ok(page.into());
+    }
+
+    // 地域の日付ごとの収集状況
+    async fn update_status(
+        &self,
+        #[graphql(validator(min_length = 1))] location_key: String,
+    ) -> Result<Vec<event_model::UpdateStatus>> {
+        let statuses = get_event_service::get_update_status(location_key)
+            .map_err(|e| Error::new(e.to_string()))?;
+        return Ok(statuses.into_iter().map(Into::into).collect());
+    }
+
+    // スパム判定ルール
+    #[graphql(guard = "RoleGuard::new(Role::Admin)")]
+    async fn spam_rule(&self) -> Result<event_model::SpamRule> {
+        let rule = spam_filter_service::get_spam_rule().map_err(|e| Error::new(e.to_string()))?;
+        return Ok(rule.into());
+    }
+}
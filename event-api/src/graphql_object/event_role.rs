@@ -0,0 +1,31 @@
+use async_graphql::*;
+
+// 管理者用のキーが指定されたリクエストでセット
+#[derive(Clone, Copy)]
+pub struct AdminContext;
+
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum Role {
+    Admin,
+    Anonymous,
+}
+
+pub struct RoleGuard {
+    pub role: Role,
+}
+
+impl RoleGuard {
+    pub fn new(role: Role) -> Self {
+        Self { role }
+    }
+}
+
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        if self.role == Role::Anonymous || ctx.data_opt::<AdminContext>().is_some() {
+            Ok(())
+        } else {
+            Err("Forbidden".into())
+        }
+    }
+}
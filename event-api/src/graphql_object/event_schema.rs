@@ -0,0 +1,14 @@
+use async_graphql::{EmptySubscription, Schema};
+
+use crate::graphql_object::{event_mutation, event_query};
+
+pub type EventSchema = Schema<event_query::Query, event_mutation::Mutation, EmptySubscription>;
+
+pub fn build_schema() -> EventSchema {
+    return Schema::build(
+        event_query::Query,
+        event_mutation::Mutation,
+        EmptySubscription,
+    )
+    .finish();
+}
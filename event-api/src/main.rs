@@ -1,13 +1,22 @@
 use actix_cors::Cors;
 use actix_files as fs;
 use actix_web::http;
+use actix_web::web::Data;
 use actix_web::App;
 use actix_web::HttpServer;
 use dotenv;
 use std::env;
 
 mod controller {
+    pub mod auth_controller;
+    pub mod event_archive_controller;
+    pub mod event_bookmark_controller;
+    pub mod event_recommend_controller;
+    pub mod event_stream_controller;
     pub mod get_event_info_controller;
+    pub mod graphql_controller;
+    pub mod manual_event_controller;
+    pub mod spam_rule_controller;
     pub mod update_event_info_controller;
 }
 
@@ -15,29 +24,76 @@ mod gather {
     pub mod gather_event_data;
 }
 
+mod graphql_object {
+    pub mod event_model;
+    pub mod event_mutation;
+    pub mod event_query;
+    pub mod event_role;
+    pub mod event_schema;
+}
+
 mod model {
     pub mod db {
+        pub mod account_user_collection;
+        pub mod event_archive_collection;
+        pub mod event_bookmark_collection;
+        pub mod event_click_collection;
         pub mod event_collection;
         pub mod event_info_collection;
+        pub mod spam_rule_collection;
     }
     pub mod api {
+        pub mod account_user_request;
+        pub mod account_user_response;
+        pub mod event_archive_stats_response;
+        pub mod event_bookmark_request;
+        pub mod event_bookmark_response;
+        pub mod event_calendar_response;
         pub mod event_info_master_response;
+        pub mod event_page_response;
+        pub mod event_recommend_request;
+        pub mod event_recommend_response;
+        pub mod event_response;
+        pub mod event_stream_message;
+        pub mod event_update_status_response;
+        pub mod manual_event_request;
+        pub mod spam_rule_request;
     }
 }
 
 mod repository {
+    pub mod account_user_repository;
+    pub mod event_archive_repository;
+    pub mod event_bookmark_repository;
+    pub mod event_click_repository;
     pub mod event_repository;
     pub mod event_search_info_repository;
     pub mod mongodb_client;
+    pub mod spam_rule_repository;
 }
 
 mod service {
+    pub mod auth {
+        pub mod account_user_service;
+        pub mod google_auth_service;
+        pub mod jwt_service;
+    }
+    pub mod event_archive_service;
+    pub mod event_bookmark_service;
+    pub mod event_recommend_service;
+    pub mod event_stream_service;
     pub mod get_event_service;
+    pub mod manual_event_service;
+    pub mod spam_filter_service;
     pub mod update_event_service;
 }
 
 mod util {
+    pub mod admin_util;
     pub mod date_util;
+    pub mod event_tag_util;
+    pub mod similarity_util;
+    pub mod url_util;
 }
 
 #[actix_web::main]
@@ -53,18 +109,45 @@ async fn main() -> std::io::Result<()> {
         .unwrap_or_else(|_| "8080".to_string())
         .parse::<u16>()
         .unwrap();
+    // 収集したイベントの配信用（全ワーカーで共有）
+    let broadcaster = Data::new(service::event_stream_service::EventStreamBroadcaster::new());
+    // graphqlスキーマ
+    let schema = Data::new(graphql_object::event_schema::build_schema());
 
-    HttpServer::new(|| {
+    HttpServer::new(move || {
         let cors = Cors::default()
             .allowed_origin(&env::var("FRONT_DOMAIN").unwrap())
             .allowed_methods(vec!["GET", "POST", "PUT", "OPTIONS", "DELETE"])
-            .allowed_header(http::header::CONTENT_TYPE);
+            .allowed_header(http::header::CONTENT_TYPE)
+            .allowed_header(http::header::AUTHORIZATION)
+            .allowed_header(util::admin_util::ADMIN_KEY_HEADER);
         App::new()
             .wrap(cors)
+            .app_data(broadcaster.clone())
+            .app_data(schema.clone())
             .service(fs::Files::new("/contents", "asset/").show_files_listing())
             .service(controller::update_event_info_controller::update_event_info)
             .service(controller::get_event_info_controller::get_event_master)
             .service(controller::get_event_info_controller::get_event_list)
+            .service(controller::get_event_info_controller::get_event_calendar_summary)
+            .service(controller::auth_controller::login_by_google)
+            .service(controller::event_bookmark_controller::get_bookmark_list)
+            .service(controller::event_bookmark_controller::save_bookmark)
+            .service(controller::event_bookmark_controller::delete_bookmark)
+            .service(controller::event_recommend_controller::get_similar_event_list)
+            .service(controller::event_recommend_controller::get_recommend_event_list)
+            .service(controller::event_recommend_controller::add_event_click)
+            .service(controller::event_archive_controller::get_event_archive_stats)
+            .service(controller::event_archive_controller::get_event_archive_site_trend)
+            .service(controller::event_stream_controller::stream_event_info)
+            .service(controller::manual_event_controller::add_manual_event)
+            .service(controller::manual_event_controller::get_pending_manual_event_list)
+            .service(controller::manual_event_controller::moderate_manual_event)
+            .service(controller::spam_rule_controller::get_spam_rule)
+            .service(controller::spam_rule_controller::update_spam_rule)
+            .service(controller::graphql_controller::graphql)
+            .service(controller::graphql_controller::graphql_get)
+            .service(controller::graphql_controller::graphql_sdl)
     })
     .bind(("0.0.0.0", port))?
     .run()
@@ -0,0 +1,6 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct GoogleAuthCodeRequest {
+    pub auth_code: String,
+}
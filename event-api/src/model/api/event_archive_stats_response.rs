@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct EventArchiveStatsResponse {
+    pub sites: Vec<EventArchiveStatsCount>,
+    pub weekdays: Vec<EventArchiveStatsCount>,
+    pub hours: Vec<EventArchiveStatsCount>,
+    pub tags: Vec<EventArchiveStatsCount>,
+}
+
+#[derive(Serialize)]
+pub struct EventArchiveStatsCount {
+    pub key: String,
+    pub count: i64,
+}
+
+#[derive(Serialize)]
+pub struct EventArchiveSiteTrendResponse {
+    pub site_id: String,
+    pub month: String,
+    pub count: i64,
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventBookmarkRequest {
+    pub site_id: String,
+    pub site_event_id: String,
+    #[serde(default)]
+    pub going: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventBookmarkDeleteRequest {
+    pub site_id: String,
+    pub site_event_id: String,
+}
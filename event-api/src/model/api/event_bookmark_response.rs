@@ -0,0 +1,30 @@
+use crate::model::db::event_bookmark_collection::EventBookmarkCollection;
+use crate::util::date_util;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventBookmarkResponse {
+    pub site_id: String,
+    pub site_event_id: String,
+    pub location_key: String,
+    pub title: String,
+    pub url: String,
+    pub event_date: String,
+    pub event_time: Option<String>,
+    pub going: bool,
+}
+
+impl EventBookmarkResponse {
+    pub fn from_collection(bookmark: EventBookmarkCollection) -> EventBookmarkResponse {
+        return EventBookmarkResponse {
+            site_id: bookmark.site_id,
+            site_event_id: bookmark.site_event_id,
+            location_key: bookmark.location_key,
+            title: bookmark.title,
+            url: bookmark.url,
+            event_date: date_util::format_bson_date(bookmark.event_date, "%Y-%m-%d"),
+            event_time: bookmark.event_time,
+            going: bookmark.going,
+        };
+    }
+}
@@ -0,0 +1,23 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct EventCalendarResponse {
+    pub location_key: String,
+    pub month: String,
+    pub days: Vec<EventCalendarDayResponse>,
+}
+
+#[derive(Serialize)]
+pub struct EventCalendarDayResponse {
+    pub event_date: String,
+    // gathered: 収集済み、not_gathered: 未収集、out_of_range: 収集対象外
+    pub gather_status: String,
+    pub total_count: i64,
+    pub site_counts: Vec<EventCalendarSiteCountResponse>,
+}
+
+#[derive(Serialize)]
+pub struct EventCalendarSiteCountResponse {
+    pub site_id: String,
+    pub count: i64,
+}
@@ -2,7 +2,7 @@ use serde::Serialize;
 
 #[derive(Serialize)]
 pub struct EventInfoMasterResponse {
-    pub locations: Vec<EventInfoMasterResponseKV>,
+    pub locations: Vec<EventInfoMasterResponseLocation>,
     pub sites: Vec<EventInfoMasterResponseKV>,
 }
 
@@ -11,3 +11,11 @@ pub struct EventInfoMasterResponseKV {
     pub key: String,
     pub label: String,
 }
+
+#[derive(Serialize)]
+pub struct EventInfoMasterResponseLocation {
+    pub key: String,
+    pub label: String,
+    // 地域で収集対象になっているサイト（優先度順）
+    pub sites: Vec<String>,
+}
@@ -0,0 +1,9 @@
+use crate::model::api::event_response::EventResponse;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventPageResponse {
+    // 条件に一致する全件数
+    pub total_count: u64,
+    pub events: Vec<EventResponse>,
+}
@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventClickRequest {
+    pub site_id: String,
+    pub site_event_id: String,
+}
@@ -0,0 +1,10 @@
+use crate::model::api::event_response::EventResponse;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventRecommendResponse {
+    #[serde(flatten)]
+    pub event: EventResponse,
+    // タイトルの類似度（0〜1）
+    pub score: f64,
+}
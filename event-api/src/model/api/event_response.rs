@@ -0,0 +1,36 @@
+use crate::model::db::event_collection::{EventCollection, EventSpamInfo};
+use crate::util::date_util;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventResponse {
+    pub site_id: String,
+    pub site_event_id: String,
+    pub location_key: String,
+    pub title: String,
+    pub url: String,
+    pub event_date: String,
+    pub event_time: Option<String>,
+    pub update_time: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spam: Option<EventSpamInfo>,
+}
+
+impl EventResponse {
+    pub fn from_collection(event: EventCollection) -> EventResponse {
+        return EventResponse {
+            site_id: event.site_id,
+            site_event_id: event.site_event_id,
+            location_key: event.location_key,
+            title: event.title,
+            url: event.url,
+            event_date: date_util::format_bson_date(event.event_date, "%Y-%m-%d"),
+            event_time: event.event_time,
+            update_time: event.update_time.timestamp_millis() / 1000,
+            approved: event.approved,
+            spam: event.spam,
+        };
+    }
+}
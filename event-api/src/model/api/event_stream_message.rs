@@ -0,0 +1,35 @@
+use crate::model::api::event_response::EventResponse;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventStreamMessage {
+    // 収集の進捗
+    Progress {
+        location_key: String,
+        message: String,
+    },
+    // 登録済みになったイベント
+    Events {
+        location_key: String,
+        event_date: String,
+        site_id: String,
+        events: Vec<EventResponse>,
+    },
+}
+
+impl EventStreamMessage {
+    pub fn location_key(&self) -> &str {
+        return match self {
+            EventStreamMessage::Progress { location_key, .. } => location_key,
+            EventStreamMessage::Events { location_key, .. } => location_key,
+        };
+    }
+
+    pub fn event_name(&self) -> &str {
+        return match self {
+            EventStreamMessage::Progress { .. } => "progress",
+            EventStreamMessage::Events { .. } => "events",
+        };
+    }
+}
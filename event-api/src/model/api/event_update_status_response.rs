@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct EventUpdateStatusResponse {
+    pub event_date: String,
+    pub gathered: bool,
+    // 最終収集日時（JST）
+    pub update_time: Option<String>,
+}
@@ -0,0 +1,17 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ManualEventRequest {
+    pub url: String,
+    pub location_key: String,
+    pub title: Option<String>,
+    pub event_date: Option<String>,
+    pub event_time: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ManualEventModerateRequest {
+    pub location_key: String,
+    pub site_event_id: String,
+    pub approved: bool,
+}
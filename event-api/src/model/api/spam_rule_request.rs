@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SpamRuleRequest {
+    pub blocklist_keywords: Vec<String>,
+    pub blocklist_title_patterns: Vec<String>,
+    pub keyword_score: i32,
+    pub pattern_score: i32,
+    pub poster_repeat_date_count: i64,
+    pub poster_repeat_score: i32,
+    pub flag_threshold: i32,
+}
@@ -0,0 +1,9 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountUserCollection {
+    pub _id: String,
+    pub gmail: String,
+    pub create_time: DateTime,
+}
@@ -0,0 +1,55 @@
+use crate::model::db::event_collection::EventCollection;
+use crate::util::{date_util, event_tag_util};
+use chrono::Datelike;
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventArchiveCollection {
+    pub site_id: String,
+    pub site_event_id: String,
+    pub location_key: String,
+    pub title: String,
+    pub url: String,
+    pub event_date: DateTime,
+    pub event_time: Option<String>,
+    pub event_weekday: i32,
+    pub event_hour: Option<i32>,
+    pub tags: Vec<String>,
+    pub archive_time: DateTime,
+}
+
+impl EventArchiveCollection {
+    pub fn from_event(event: EventCollection, archive_time: DateTime) -> EventArchiveCollection {
+        // 曜日（日曜日が0）
+        let event_weekday = date_util::from_bson_date_time(event.event_date)
+            .weekday()
+            .num_days_from_sunday() as i32;
+        // 開始時間の時部分
+        let event_hour = event.event_time.as_ref().and_then(|t| {
+            let hour_str: String = t
+                .trim()
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            match hour_str.parse::<i32>() {
+                Ok(h) if (0..=24).contains(&h) => Some(h),
+                _ => None,
+            }
+        });
+        let tags = event_tag_util::get_event_tags(&event.title);
+        return EventArchiveCollection {
+            site_id: event.site_id,
+            site_event_id: event.site_event_id,
+            location_key: event.location_key,
+            title: event.title,
+            url: event.url,
+            event_date: event.event_date,
+            event_time: event.event_time,
+            event_weekday,
+            event_hour,
+            tags,
+            archive_time,
+        };
+    }
+}
@@ -0,0 +1,43 @@
+use crate::model::db::event_collection::EventCollection;
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+// イベントのブックマーク（元のイベント削除後も表示できるようにスナップショットを保持）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventBookmarkCollection {
+    pub _id: String,
+    pub account_id: String,
+    pub site_id: String,
+    pub site_event_id: String,
+    pub location_key: String,
+    pub title: String,
+    pub url: String,
+    pub event_date: DateTime,
+    pub event_time: Option<String>,
+    pub going: bool,
+    pub create_time: DateTime,
+}
+
+impl EventBookmarkCollection {
+    pub fn from_event(
+        id: String,
+        account_id: String,
+        event: EventCollection,
+        going: bool,
+        create_time: DateTime,
+    ) -> EventBookmarkCollection {
+        return EventBookmarkCollection {
+            _id: id,
+            account_id,
+            site_id: event.site_id,
+            site_event_id: event.site_event_id,
+            location_key: event.location_key,
+            title: event.title,
+            url: event.url,
+            event_date: event.event_date,
+            event_time: event.event_time,
+            going,
+            create_time,
+        };
+    }
+}
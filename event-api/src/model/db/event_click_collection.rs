@@ -0,0 +1,34 @@
+use crate::model::db::event_collection::EventCollection;
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+// ユーザーがクリックしたイベントの履歴
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventClickCollection {
+    pub _id: String,
+    pub account_id: String,
+    pub site_id: String,
+    pub site_event_id: String,
+    pub location_key: String,
+    pub title: String,
+    pub create_time: DateTime,
+}
+
+impl EventClickCollection {
+    pub fn from_event(
+        id: String,
+        account_id: String,
+        event: EventCollection,
+        create_time: DateTime,
+    ) -> EventClickCollection {
+        return EventClickCollection {
+            _id: id,
+            account_id,
+            site_id: event.site_id,
+            site_event_id: event.site_event_id,
+            location_key: event.location_key,
+            title: event.title,
+            create_time,
+        };
+    }
+}
@@ -1,6 +1,8 @@
+use crate::model::api::manual_event_request::ManualEventRequest;
 use crate::util::date_util;
-use chrono::{DateTime, Datelike};
+use chrono::{DateTime as ChronoDateTime, Datelike};
 use chrono_tz::Tz;
+use mongodb::bson::DateTime;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
 
@@ -11,17 +13,29 @@ pub struct EventCollection {
     pub location_key: String,
     pub title: String,
     pub url: String,
-    pub event_date: String,
+    pub event_date: DateTime,
     pub event_time: Option<String>,
-    pub update_time: i64,
+    pub update_time: DateTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approved: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poster_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spam: Option<EventSpamInfo>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventSpamInfo {
+    pub score: i32,
+    pub flagged: bool,
 }
 
 impl EventCollection {
     pub fn from_tunagate_json(
         json: String,
         location_key: String,
-        event_date: String,
-        update_time: i64,
+        event_date: DateTime,
+        update_time: DateTime,
     ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
         let mut result_vec: Vec<EventCollection> = Vec::new();
         let v: serde_json::Value = serde_json::from_str(&json)?;
@@ -63,9 +77,12 @@ impl EventCollection {
                         location_key: location_key.clone(),
                         title: event_title.to_string(),
                         url: event_url,
-                        event_date: event_date.clone(),
+                        event_date: event_date,
                         event_time: Some(event_time.to_string()),
                         update_time: update_time,
+                        approved: None,
+                        poster_id: None,
+                        spam: None,
                     })
                 }
             }
@@ -76,16 +93,20 @@ impl EventCollection {
     pub fn from_jmty_html(
         html: String,
         location_key: String,
-        event_date: String,
-        update_time: i64,
+        event_date: DateTime,
+        update_time: DateTime,
     ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
         let mut result_vec: Vec<EventCollection> = Vec::new();
         let doc = scraper::Html::parse_document(&html);
-        let title_a_tag = scraper::Selector::parse(
-            "li.p-articles-list-item div.p-item-content-info div.p-item-title a",
-        )
-        .unwrap();
-        for node in doc.select(&title_a_tag) {
+        let item_tag = scraper::Selector::parse("li.p-articles-list-item").unwrap();
+        let title_a_tag =
+            scraper::Selector::parse("div.p-item-content-info div.p-item-title a").unwrap();
+        let poster_a_tag = scraper::Selector::parse("a[href*='/profiles/']").unwrap();
+        for item_node in doc.select(&item_tag) {
+            let node = match item_node.select(&title_a_tag).next() {
+                Some(n) => n,
+                None => continue,
+            };
             let node_ref = &node;
             if let Some(href) = node_ref.value().attr("href") {
                 let href_split: Vec<&str> = href.clone().split("/").collect();
@@ -94,6 +115,13 @@ impl EventCollection {
                     + "/"
                     + href_split[href_split_size - 1];
                 let event_title = node_ref.text().collect::<Vec<_>>()[0].trim();
+                // 投稿者（プロフィールのURLの末尾）
+                let poster_id = item_node
+                    .select(&poster_a_tag)
+                    .next()
+                    .and_then(|p| p.value().attr("href"))
+                    .and_then(|p| p.trim_end_matches('/').rsplit('/').next())
+                    .map(|p| p.to_string());
                 // 結果をVecに追加
                 result_vec.push(EventCollection {
                     site_id: "jmty".to_string(),
@@ -101,9 +129,12 @@ impl EventCollection {
                     location_key: location_key.clone(),
                     title: event_title.to_string(),
                     url: href.to_string(),
-                    event_date: event_date.clone(),
+                    event_date: event_date,
                     event_time: None,
                     update_time: update_time,
+                    approved: None,
+                    poster_id,
+                    spam: None,
                 })
             }
         }
@@ -113,8 +144,8 @@ impl EventCollection {
     pub fn from_koryupa_html(
         html: String,
         location_key: String,
-        event_date: String,
-        update_time: i64,
+        event_date: DateTime,
+        update_time: DateTime,
     ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
         let mut result_vec: Vec<EventCollection> = Vec::new();
         let doc = scraper::Html::parse_document(&html);
@@ -140,9 +171,12 @@ impl EventCollection {
                         location_key: location_key.clone(),
                         title: event_title.to_string(),
                         url: url,
-                        event_date: event_date.clone(),
+                        event_date: event_date,
                         event_time: None,
                         update_time: update_time,
+                        approved: None,
+                        poster_id: None,
+                        spam: None,
                     })
                 }
             }
@@ -153,9 +187,9 @@ impl EventCollection {
     pub fn from_kokuchpro_html(
         html: String,
         location_key: String,
-        event_date: String,
-        event_date_time: DateTime<Tz>,
-        update_time: i64,
+        event_date: DateTime,
+        event_date_time: ChronoDateTime<Tz>,
+        update_time: DateTime,
     ) -> Result<(Vec<EventCollection>, bool), Box<dyn Error>> {
         let mut result_vec: Vec<EventCollection> = Vec::new();
         let doc = scraper::Html::parse_document(&html);
@@ -204,9 +238,12 @@ impl EventCollection {
                                     location_key: location_key.clone(),
                                     title: event_title.to_string(),
                                     url: href.to_string(),
-                                    event_date: event_date.clone(),
+                                    event_date: event_date,
                                     event_time: Some(event_time.to_string()),
                                     update_time: update_time,
+                                    approved: None,
+                                    poster_id: None,
+                                    spam: None,
                                 })
                             } else {
                                 // 他の日付の場合が入ってる場合はこの時点でreturn
@@ -226,9 +263,9 @@ impl EventCollection {
     pub fn from_twipla_html(
         html: String,
         location_key: String,
-        event_date: String,
-        event_date_time: DateTime<Tz>,
-        update_time: i64,
+        event_date: DateTime,
+        event_date_time: ChronoDateTime<Tz>,
+        update_time: DateTime,
     ) -> Result<(Vec<EventCollection>, bool), Box<dyn Error>> {
         let mut result_vec: Vec<EventCollection> = Vec::new();
         let doc = scraper::Html::parse_document(&html);
@@ -275,9 +312,12 @@ impl EventCollection {
                                     location_key: location_key.clone(),
                                     title: event_title.to_string(),
                                     url: "https://twipla.jp".to_string() + href,
-                                    event_date: event_date.clone(),
+                                    event_date: event_date,
                                     event_time: event_time,
                                     update_time: update_time,
+                                    approved: None,
+                                    poster_id: None,
+                                    spam: None,
                                 })
                             }
                         }
@@ -292,4 +332,98 @@ impl EventCollection {
         }
         return Ok((result_vec, true));
     }
+
+    pub fn from_manual_html(
+        html: String,
+        request: ManualEventRequest,
+        update_time: DateTime,
+    ) -> Result<EventCollection, Box<dyn Error>> {
+        let doc = scraper::Html::parse_document(&html);
+        // メタ情報の属性値を取得
+        let select_attr = |selector: &str, attr: &str| -> Option<String> {
+            let tag = scraper::Selector::parse(selector).unwrap();
+            doc.select(&tag)
+                .filter_map(|n| n.value().attr(attr))
+                .map(|v| v.trim().to_string())
+                .find(|v| !v.is_empty())
+        };
+        // 構造化データ（JSON-LD）のイベント情報
+        let json_ld_tag = scraper::Selector::parse("script[type='application/ld+json']").unwrap();
+        let json_ld_event = doc
+            .select(&json_ld_tag)
+            .filter_map(|n| serde_json::from_str::<serde_json::Value>(&n.inner_html()).ok())
+            .find_map(|v| find_json_ld_event(&v).cloned());
+        // タイトル
+        let meta_title = select_attr("meta[property='og:title']", "content")
+            .or_else(|| {
+                json_ld_event
+                    .as_ref()
+                    .and_then(|e| e["name"].as_str().map(|n| n.trim().to_string()))
+            })
+            .or_else(|| {
+                let title_tag = scraper::Selector::parse("title").unwrap();
+                doc.select(&title_tag)
+                    .next()
+                    .map(|n| n.text().collect::<String>().trim().to_string())
+            });
+        // 開始日時
+        let meta_start = select_attr("meta[property='event:start_time']", "content")
+            .or_else(|| select_attr("[itemprop='startDate']", "content"))
+            .or_else(|| select_attr("[itemprop='startDate']", "datetime"))
+            .or_else(|| {
+                json_ld_event
+                    .as_ref()
+                    .and_then(|e| e["startDate"].as_str().map(|d| d.to_string()))
+            });
+        let (meta_date, meta_time) = match meta_start {
+            Some(start) => match date_util::parse_iso_str_jst_date_time(&start) {
+                Ok((d, has_time)) => (
+                    Some(date_util::format_jst_date(d, "%Y-%m-%d")),
+                    if has_time {
+                        Some(date_util::format_jst_date(d, "%H:%M"))
+                    } else {
+                        None
+                    },
+                ),
+                Err(_e) => (None, None),
+            },
+            None => (None, None),
+        };
+        // 指定された値を優先
+        let event_title = match request.title.or(meta_title) {
+            Some(t) if !t.is_empty() => t,
+            _ => return Err("Can not get event title".into()),
+        };
+        let event_date = match request.event_date.or(meta_date) {
+            Some(d) => date_util::parse_str_bson_date(d)?,
+            None => return Err("Can not get event date".into()),
+        };
+        return Ok(EventCollection {
+            site_id: "manual".to_string(),
+            site_event_id: request.url.clone(),
+            location_key: request.location_key,
+            title: event_title,
+            url: request.url,
+            event_date,
+            event_time: request.event_time.or(meta_time),
+            update_time,
+            approved: Some(false),
+            poster_id: None,
+            spam: None,
+        });
+    }
+}
+
+// JSON-LDからstartDateを持つオブジェクトを探す
+fn find_json_ld_event(value: &serde_json::Value) -> Option<&serde_json::Value> {
+    match value {
+        serde_json::Value::Object(map) => {
+            if map.contains_key("startDate") {
+                return Some(value);
+            }
+            map.get("@graph").and_then(find_json_ld_event)
+        }
+        serde_json::Value::Array(arr) => arr.iter().find_map(find_json_ld_event),
+        _ => None,
+    }
 }
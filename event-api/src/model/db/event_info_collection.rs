@@ -1,42 +1,40 @@
-use crate::util::date_util;
+use mongodb::bson::DateTime;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EventUpdateHistoryCollection {
     pub location_key: String,
-    pub event_date: String,
-    pub update_time: i64,
-}
-
-impl EventUpdateHistoryCollection {
-    pub fn is_update_target(&self, target_time: i64) -> bool {
-        // 現在時時刻から2日以内の更新なら対象外
-        let now_time = date_util::get_now_jst_date_time().timestamp();
-        if now_time - self.update_time < date_util::DATE_SEC * 2 {
-            return false;
-        }
-
-        // target_time以降
-        return match date_util::parse_str_jst_date(self.event_date.clone()) {
-            Ok(r) => r.timestamp() >= target_time,
-            Err(_e) => false,
-        };
-    }
-    pub fn is_delete_target(&self, target_time: i64) -> bool {
-        // target_timeより前
-        return match date_util::parse_str_jst_date(self.event_date.clone()) {
-            Ok(r) => r.timestamp() < target_time,
-            Err(_e) => false,
-        };
-    }
+    pub event_date: DateTime,
+    // 未収集の場合はNone
+    pub update_time: Option<DateTime>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EventSearchMasterCollection {
     pub _id: String,
-    pub tunagate_key: String,
-    pub jmty_key: String,
-    pub koryupa_key: String,
-    pub kokuchpro_key: String,
-    pub twipla_key: String,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub sites: Vec<EventSearchSiteSetting>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EventSearchSiteSetting {
+    pub site_id: String,
+    pub search_key: Option<String>,
+    pub enabled: bool,
+    pub priority: i32,
+}
+
+impl EventSearchMasterCollection {
+    // 収集対象のサイト（有効かつ検索キーが設定されているもの）を優先度順で返す
+    pub fn get_enabled_sites(&self) -> Vec<EventSearchSiteSetting> {
+        let mut sites: Vec<EventSearchSiteSetting> = self
+            .sites
+            .iter()
+            .filter(|s| s.enabled && s.search_key.as_ref().is_some_and(|k| !k.is_empty()))
+            .cloned()
+            .collect();
+        sites.sort_by_key(|s| s.priority);
+        return sites;
+    }
 }
@@ -0,0 +1,65 @@
+use mongodb::bson::DateTime;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+// サイトごとのスパム判定ルール（_idはサイトID）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpamRuleCollection {
+    pub _id: String,
+    // タイトルに含まれていたら加点するキーワード
+    pub blocklist_keywords: Vec<String>,
+    // タイトルが一致したら加点する正規表現
+    pub blocklist_title_patterns: Vec<String>,
+    pub keyword_score: i32,
+    pub pattern_score: i32,
+    // 同じ投稿者がこの日数以上に投稿していたら加点
+    pub poster_repeat_date_count: i64,
+    pub poster_repeat_score: i32,
+    // この点数以上でスパムと判定
+    pub flag_threshold: i32,
+    pub update_time: Option<DateTime>,
+}
+
+impl SpamRuleCollection {
+    pub fn default_rule(site_id: &str) -> SpamRuleCollection {
+        return SpamRuleCollection {
+            _id: site_id.to_string(),
+            blocklist_keywords: Vec::new(),
+            blocklist_title_patterns: Vec::new(),
+            keyword_score: 2,
+            pattern_score: 2,
+            poster_repeat_date_count: 5,
+            poster_repeat_score: 1,
+            flag_threshold: 2,
+            update_time: None,
+        };
+    }
+
+    // 正規表現のコンパイル（不正なパターンはエラー）
+    pub fn compile_title_patterns(&self) -> Result<Vec<Regex>, regex::Error> {
+        return self
+            .blocklist_title_patterns
+            .iter()
+            .map(|p| Regex::new(p))
+            .collect();
+    }
+
+    // タイトルと投稿者の投稿日数から点数を計算
+    pub fn calc_score(&self, patterns: &[Regex], title: &str, poster_date_count: i64) -> i32 {
+        let mut score = 0;
+        if self
+            .blocklist_keywords
+            .iter()
+            .any(|k| !k.is_empty() && title.contains(k.as_str()))
+        {
+            score += self.keyword_score;
+        }
+        if patterns.iter().any(|p| p.is_match(title)) {
+            score += self.pattern_score;
+        }
+        if self.poster_repeat_date_count > 0 && poster_date_count >= self.poster_repeat_date_count {
+            score += self.poster_repeat_score;
+        }
+        return score;
+    }
+}
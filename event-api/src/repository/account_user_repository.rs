@@ -0,0 +1,20 @@
+use crate::model::db::account_user_collection::AccountUserCollection;
+use crate::repository::mongodb_client;
+use mongodb::bson::doc;
+use std::error::Error;
+
+pub fn get_account_user_by_gmail(
+    gmail: String,
+) -> Result<Option<AccountUserCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<AccountUserCollection>("account_user");
+    let result = col.find_one(doc! { "gmail": gmail }, None)?;
+    return Ok(result);
+}
+
+pub fn add_account_user(account_user: AccountUserCollection) -> Result<(), Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<AccountUserCollection>("account_user");
+    col.insert_one(account_user, None)?;
+    return Ok(());
+}
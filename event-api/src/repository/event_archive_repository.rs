@@ -0,0 +1,120 @@
+use crate::model::db::event_archive_collection::EventArchiveCollection;
+use crate::repository::mongodb_client;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::options::ReplaceOptions;
+use std::error::Error;
+
+pub struct EventArchiveSiteMonthCount {
+    pub site_id: String,
+    pub month: String,
+    pub count: i64,
+}
+
+pub fn add_event_archives(
+    add_archive_collections: Vec<EventArchiveCollection>,
+) -> Result<(), Box<dyn Error>> {
+    if add_archive_collections.is_empty() {
+        return Ok(());
+    }
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventArchiveCollection>("event_archive");
+    // 同じイベントを二重にアーカイブしないよう上書き
+    for archive in add_archive_collections {
+        let options = ReplaceOptions::builder().upsert(true).build();
+        col.replace_one(
+            doc! {
+                "site_id": archive.site_id.clone(),
+                "site_event_id": archive.site_event_id.clone(),
+                "location_key": archive.location_key.clone(),
+                "event_date": archive.event_date,
+            },
+            archive,
+            options,
+        )?;
+    }
+    return Ok(());
+}
+
+// 指定した項目ごとの件数を集計
+pub fn count_event_archive_by_field(
+    match_query: Document,
+    group_field: &str,
+    unwind: bool,
+) -> Result<Vec<(String, i64)>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventArchiveCollection>("event_archive");
+    let mut pipeline = vec![doc! { "$match": match_query }];
+    if unwind {
+        pipeline.push(doc! { "$unwind": format!("${}", group_field) });
+    }
+    pipeline.push(doc! {
+        "$group": { "_id": format!("${}", group_field), "count": { "$sum": 1 } }
+    });
+    pipeline.push(doc! { "$sort": { "_id": 1 } });
+    let results = col
+        .aggregate(pipeline, None)?
+        .flatten()
+        .map(|d| {
+            (
+                bson_to_key_string(d.get("_id")),
+                bson_to_count(d.get("count")),
+            )
+        })
+        .collect();
+    return Ok(results);
+}
+
+// サイトと月ごとの件数を集計
+pub fn count_event_archive_by_site_month(
+    match_query: Document,
+) -> Result<Vec<EventArchiveSiteMonthCount>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventArchiveCollection>("event_archive");
+    let pipeline = vec![
+        doc! { "$match": match_query },
+        doc! {
+            "$group": {
+                "_id": {
+                    "site_id": "$site_id",
+                    "month": {
+                        "$dateToString": {
+                            "format": "%Y-%m", "date": "$event_date", "timezone": "Asia/Tokyo"
+                        }
+                    }
+                },
+                "count": { "$sum": 1 }
+            }
+        },
+        doc! { "$sort": { "_id.month": 1, "_id.site_id": 1 } },
+    ];
+    let results = col
+        .aggregate(pipeline, None)?
+        .flatten()
+        .filter_map(|d| {
+            let id = d.get_document("_id").ok()?;
+            Some(EventArchiveSiteMonthCount {
+                site_id: bson_to_key_string(id.get("site_id")),
+                month: bson_to_key_string(id.get("month")),
+                count: bson_to_count(d.get("count")),
+            })
+        })
+        .collect();
+    return Ok(results);
+}
+
+fn bson_to_key_string(value: Option<&Bson>) -> String {
+    return match value {
+        Some(Bson::String(s)) => s.clone(),
+        Some(Bson::Int32(i)) => i.to_string(),
+        Some(Bson::Int64(i)) => i.to_string(),
+        _ => "unknown".to_string(),
+    };
+}
+
+fn bson_to_count(value: Option<&Bson>) -> i64 {
+    return match value {
+        Some(Bson::Int32(i)) => *i as i64,
+        Some(Bson::Int64(i)) => *i,
+        _ => 0,
+    };
+}
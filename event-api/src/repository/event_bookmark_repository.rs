@@ -0,0 +1,72 @@
+use crate::model::db::event_bookmark_collection::EventBookmarkCollection;
+use crate::repository::mongodb_client;
+use mongodb::bson::doc;
+use mongodb::options::FindOptions;
+use std::error::Error;
+
+pub fn get_bookmark(
+    account_id: String,
+    site_id: String,
+    site_event_id: String,
+) -> Result<Option<EventBookmarkCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventBookmarkCollection>("event_bookmark");
+    let query = doc! {
+        "account_id": account_id, "site_id": site_id, "site_event_id": site_event_id
+    };
+    let result = col.find_one(query, None)?;
+    return Ok(result);
+}
+
+pub fn get_bookmarks(
+    account_id: String,
+    going_only: bool,
+) -> Result<Vec<EventBookmarkCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventBookmarkCollection>("event_bookmark");
+    let mut query = doc! { "account_id": account_id };
+    if going_only {
+        query.insert("going", true);
+    }
+    let find_options = FindOptions::builder()
+        .sort(doc! { "event_date": -1, "create_time": -1 })
+        .build();
+    let results = col.find(query, find_options)?.flatten().collect();
+    return Ok(results);
+}
+
+pub fn add_bookmark(bookmark: EventBookmarkCollection) -> Result<(), Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventBookmarkCollection>("event_bookmark");
+    col.insert_one(bookmark, None)?;
+    return Ok(());
+}
+
+pub fn update_bookmark_going(id: String, going: bool) -> Result<(), Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventBookmarkCollection>("event_bookmark");
+    col.update_one(
+        doc! { "_id": id },
+        doc! {
+            "$set": { "going": going }
+        },
+        None,
+    )?;
+    return Ok(());
+}
+
+pub fn delete_bookmark(
+    account_id: String,
+    site_id: String,
+    site_event_id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventBookmarkCollection>("event_bookmark");
+    let result = col.delete_one(
+        doc! {
+            "account_id": account_id, "site_id": site_id, "site_event_id": site_event_id
+        },
+        None,
+    )?;
+    return Ok(result.deleted_count);
+}
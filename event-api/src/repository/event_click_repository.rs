@@ -0,0 +1,29 @@
+use crate::model::db::event_click_collection::EventClickCollection;
+use crate::repository::mongodb_client;
+use mongodb::bson::doc;
+use mongodb::options::FindOptions;
+use std::error::Error;
+
+pub fn add_event_click(event_click: EventClickCollection) -> Result<(), Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventClickCollection>("event_click");
+    col.insert_one(event_click, None)?;
+    return Ok(());
+}
+
+pub fn get_recent_event_clicks(
+    account_id: String,
+    limit: i64,
+) -> Result<Vec<EventClickCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventClickCollection>("event_click");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "create_time": -1 })
+        .limit(limit)
+        .build();
+    let results = col
+        .find(doc! { "account_id": account_id }, find_options)?
+        .flatten()
+        .collect();
+    return Ok(results);
+}
@@ -1,9 +1,20 @@
-use crate::model::db::event_collection::EventCollection;
+use crate::model::db::event_collection::{EventCollection, EventSpamInfo};
 use crate::repository::mongodb_client;
-use mongodb::bson::doc;
+use mongodb::bson::{doc, Bson, DateTime};
+use mongodb::options::FindOptions;
+use std::collections::HashMap;
 use std::error::Error;
 
+pub struct EventDateSiteCount {
+    pub event_date: String,
+    pub site_id: String,
+    pub count: i64,
+}
+
 pub fn add_events(add_event_collections: Vec<EventCollection>) -> Result<(), Box<dyn Error>> {
+    if add_event_collections.is_empty() {
+        return Ok(());
+    }
     let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
     col.insert_many(add_event_collections, None)?;
     return Ok(());
@@ -11,25 +22,320 @@ pub fn add_events(add_event_collections: Vec<EventCollection>) -> Result<(), Box
 
 pub fn delete_events(
     location_key: String,
-    event_date: String,
-    update_time: i64,
+    event_date: DateTime,
+    update_time: DateTime,
 ) -> Result<(), Box<dyn Error>> {
     let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
     col.delete_many(
         doc! {
-            "location_key": location_key, "event_date": event_date, "update_time": update_time
+            "location_key": location_key,
+            "event_date": event_date,
+            "update_time": update_time,
+            // 手動登録のイベントは再収集時に削除しない
+            "site_id": { "$ne": "manual" }
         },
         None,
     )?;
     return Ok(());
 }
 
+pub fn get_archive_target_events(
+    location_key: String,
+    event_date: DateTime,
+    update_time: Option<DateTime>,
+) -> Result<Vec<EventCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    // 収集したイベントと承認済みの手動登録イベント（スパム判定されたものは除く）
+    let query = doc! {
+        "location_key": location_key,
+        "event_date": event_date,
+        "spam.flagged": { "$ne": true },
+        "$or": [
+            { "update_time": update_time, "site_id": { "$ne": "manual" } },
+            { "site_id": "manual", "approved": true }
+        ]
+    };
+    let results = col.find(query, None)?.flatten().collect();
+    return Ok(results);
+}
+
 pub fn get_events(
     location_key: String,
-    event_date: String,
+    event_date: DateTime,
+    include_flagged: bool,
 ) -> Result<Vec<EventCollection>, Box<dyn Error>> {
     let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
-    let query = doc! { "location_key": location_key , "event_date":  event_date };
+    // 承認待ちのイベントは除外
+    let mut query = doc! {
+        "location_key": location_key,
+        "event_date": event_date,
+        "approved": { "$ne": false }
+    };
+    // スパム判定されたイベントは指定時のみ
+    if !include_flagged {
+        query.insert("spam.flagged", doc! { "$ne": true });
+    }
     let results = col.find(query, None)?.flatten().collect();
     return Ok(results);
 }
+
+// 条件に一致するイベントの指定範囲と全件数
+pub fn search_events(
+    location_key: String,
+    event_date: DateTime,
+    include_flagged: bool,
+    site_ids: Option<Vec<String>>,
+    keyword: Option<String>,
+    offset: u64,
+    limit: i64,
+) -> Result<(Vec<EventCollection>, u64), Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    // 承認待ちのイベントは除外
+    let mut query = doc! {
+        "location_key": location_key,
+        "event_date": event_date,
+        "approved": { "$ne": false }
+    };
+    if !include_flagged {
+        query.insert("spam.flagged", doc! { "$ne": true });
+    }
+    if let Some(site_ids) = site_ids {
+        query.insert("site_id", doc! { "$in": site_ids });
+    }
+    // タイトルの部分一致
+    if let Some(keyword) = keyword {
+        query.insert("title", doc! { "$regex": regex::escape(&keyword) });
+    }
+    let total_count = col.count_documents(query.clone(), None)?;
+    let find_options = FindOptions::builder()
+        .sort(doc! { "_id": 1 })
+        .skip(offset)
+        .limit(limit)
+        .build();
+    let results = col.find(query, find_options)?.flatten().collect();
+    return Ok((results, total_count));
+}
+
+// 指定日以降の公開中のイベント
+pub fn get_upcoming_events(
+    location_keys: Vec<String>,
+    from_date: DateTime,
+) -> Result<Vec<EventCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let query = doc! {
+        "location_key": { "$in": location_keys },
+        "event_date": { "$gte": from_date },
+        "approved": { "$ne": false },
+        "spam.flagged": { "$ne": true }
+    };
+    let results = col.find(query, None)?.flatten().collect();
+    return Ok(results);
+}
+
+pub fn get_event_by_site_event_id(
+    site_id: String,
+    site_event_id: String,
+) -> Result<Option<EventCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let query = doc! {
+        "site_id": site_id,
+        "site_event_id": site_event_id,
+        "approved": { "$ne": false }
+    };
+    let result = col.find_one(query, None)?;
+    return Ok(result);
+}
+
+pub fn get_manual_event(
+    location_key: String,
+    site_event_id: String,
+) -> Result<Option<EventCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let query = doc! {
+        "site_id": "manual", "location_key": location_key, "site_event_id": site_event_id
+    };
+    let result = col.find_one(query, None)?;
+    return Ok(result);
+}
+
+pub fn get_pending_manual_events() -> Result<Vec<EventCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "location_key": 1, "event_date": 1 })
+        .build();
+    let query = doc! { "site_id": "manual", "approved": false };
+    let results = col.find(query, find_options)?.flatten().collect();
+    return Ok(results);
+}
+
+pub fn approve_manual_event(
+    location_key: String,
+    site_event_id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let result = col.update_one(
+        doc! {
+            "site_id": "manual", "location_key": location_key, "site_event_id": site_event_id
+        },
+        doc! {
+            "$set": { "approved": true }
+        },
+        None,
+    )?;
+    return Ok(result.matched_count);
+}
+
+pub fn delete_manual_event(
+    location_key: String,
+    site_event_id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let result = col.delete_one(
+        doc! {
+            "site_id": "manual", "location_key": location_key, "site_event_id": site_event_id
+        },
+        None,
+    )?;
+    return Ok(result.deleted_count);
+}
+
+pub fn delete_manual_events(
+    location_key: String,
+    event_dates: Vec<DateTime>,
+) -> Result<(), Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    col.delete_many(
+        doc! {
+            "site_id": "manual",
+            "location_key": location_key,
+            "event_date": { "$in": event_dates }
+        },
+        None,
+    )?;
+    return Ok(());
+}
+
+// 日付・サイトごとのイベント件数を集計
+pub fn count_events_by_date_site(
+    location_key: String,
+    from_date: DateTime,
+    to_date: DateTime,
+) -> Result<Vec<EventDateSiteCount>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let pipeline = vec![
+        doc! {
+            "$match": {
+                "location_key": location_key,
+                "event_date": { "$gte": from_date, "$lte": to_date },
+                "approved": { "$ne": false },
+                "spam.flagged": { "$ne": true }
+            }
+        },
+        doc! {
+            "$group": {
+                "_id": {
+                    "event_date": {
+                        "$dateToString": {
+                            "format": "%Y-%m-%d", "date": "$event_date", "timezone": "Asia/Tokyo"
+                        }
+                    },
+                    "site_id": "$site_id"
+                },
+                "count": { "$sum": 1 }
+            }
+        },
+    ];
+    let results = col
+        .aggregate(pipeline, None)?
+        .flatten()
+        .filter_map(|d| {
+            let id = d.get_document("_id").ok()?;
+            let count = match d.get("count") {
+                Some(Bson::Int32(i)) => *i as i64,
+                Some(Bson::Int64(i)) => *i,
+                _ => 0,
+            };
+            Some(EventDateSiteCount {
+                event_date: id.get_str("event_date").ok()?.to_string(),
+                site_id: id.get_str("site_id").ok()?.to_string(),
+                count,
+            })
+        })
+        .collect();
+    return Ok(results);
+}
+
+pub fn get_site_events(site_id: String) -> Result<Vec<EventCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let results = col
+        .find(doc! { "site_id": site_id }, None)?
+        .flatten()
+        .collect();
+    return Ok(results);
+}
+
+// 地域内の指定したイベント（サイトのイベントIDと日付）のスパム判定をまとめて更新
+pub fn update_events_spam(
+    site_id: String,
+    location_key: String,
+    event_keys: Vec<(String, DateTime)>,
+    spam: EventSpamInfo,
+) -> Result<(), Box<dyn Error>> {
+    if event_keys.is_empty() {
+        return Ok(());
+    }
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let targets: Vec<_> = event_keys
+        .into_iter()
+        .map(|(site_event_id, event_date)| {
+            doc! { "site_event_id": site_event_id, "event_date": event_date }
+        })
+        .collect();
+    col.update_many(
+        doc! {
+            "site_id": site_id,
+            "location_key": location_key,
+            "$or": targets
+        },
+        doc! {
+            "$set": { "spam": { "score": spam.score, "flagged": spam.flagged } }
+        },
+        None,
+    )?;
+    return Ok(());
+}
+
+// 投稿者ごとの投稿日数を集計（除外する日付を指定可能）
+pub fn count_dates_by_poster(
+    site_id: String,
+    location_key: String,
+    exclude_event_date: Option<DateTime>,
+) -> Result<HashMap<String, i64>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?.collection::<EventCollection>("event");
+    let mut match_query = doc! {
+        "site_id": site_id,
+        "location_key": location_key,
+        "poster_id": { "$ne": null }
+    };
+    if let Some(d) = exclude_event_date {
+        match_query.insert("event_date", doc! { "$ne": d });
+    }
+    let pipeline = vec![
+        doc! { "$match": match_query },
+        doc! { "$group": { "_id": { "poster_id": "$poster_id", "event_date": "$event_date" } } },
+        doc! { "$group": { "_id": "$_id.poster_id", "count": { "$sum": 1 } } },
+    ];
+    let results = col
+        .aggregate(pipeline, None)?
+        .flatten()
+        .filter_map(|d| {
+            let count = match d.get("count") {
+                Some(Bson::Int32(i)) => *i as i64,
+                Some(Bson::Int64(i)) => *i,
+                _ => 0,
+            };
+            Some((d.get_str("_id").ok()?.to_string(), count))
+        })
+        .collect();
+    return Ok(results);
+}
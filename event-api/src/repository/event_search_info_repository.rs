@@ -3,10 +3,10 @@ use crate::model::db::event_info_collection::{
 };
 use crate::repository::mongodb_client;
 use crate::util::date_util;
-use chrono::{DateTime, Duration};
+use chrono::{DateTime as ChronoDateTime, Duration};
 use chrono_tz::Tz;
-use mongodb::bson::doc;
-use mongodb::options::FindOptions;
+use mongodb::bson::{doc, DateTime};
+use mongodb::options::{FindOneOptions, FindOptions};
 use std::error::Error;
 
 pub fn get_event_search_master() -> Result<Vec<EventSearchMasterCollection>, Box<dyn Error>> {
@@ -16,19 +16,94 @@ pub fn get_event_search_master() -> Result<Vec<EventSearchMasterCollection>, Box
     return Ok(results);
 }
 
-pub fn get_event_update_history() -> Result<Vec<EventUpdateHistoryCollection>, Box<dyn Error>> {
+pub fn get_update_target_event_update_history(
+    location_key: String,
+    now_date: DateTime,
+    updated_before: DateTime,
+    limit: i64,
+) -> Result<Vec<EventUpdateHistoryCollection>, Box<dyn Error>> {
     let col = mongodb_client::get_mongodb_db_connection()?
         .collection::<EventUpdateHistoryCollection>("event_update_history");
+    // 当日以降で、未収集または最終更新が指定日時より前のもの
+    let query = doc! {
+        "location_key": location_key,
+        "event_date": { "$gte": now_date },
+        "$or": [
+            { "update_time": null },
+            { "update_time": { "$lt": updated_before } }
+        ]
+    };
     let find_options = FindOptions::builder()
-        .sort(doc! { "location_key": 1, "update_time": 1, "event_date": 1  })
+        .sort(doc! { "update_time": 1, "event_date": 1 })
+        .limit(limit)
         .build();
-    let results = col.find(None, find_options)?.flatten().collect();
+    let results = col.find(query, find_options)?.flatten().collect();
+    return Ok(results);
+}
+
+pub fn get_delete_target_event_update_history(
+    location_key: String,
+    now_date: DateTime,
+) -> Result<Vec<EventUpdateHistoryCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventUpdateHistoryCollection>("event_update_history");
+    let query = doc! { "location_key": location_key, "event_date": { "$lt": now_date } };
+    let results = col.find(query, None)?.flatten().collect();
+    return Ok(results);
+}
+
+pub fn count_event_update_history(location_key: String) -> Result<u64, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventUpdateHistoryCollection>("event_update_history");
+    let count = col.count_documents(doc! { "location_key": location_key }, None)?;
+    return Ok(count);
+}
+
+pub fn get_max_event_date_event_update_history(
+    location_key: String,
+) -> Result<Option<DateTime>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventUpdateHistoryCollection>("event_update_history");
+    let find_options = FindOneOptions::builder()
+        .sort(doc! { "event_date": -1 })
+        .build();
+    let result = col.find_one(doc! { "location_key": location_key }, find_options)?;
+    return Ok(result.map(|h| h.event_date));
+}
+
+pub fn get_event_update_history_by_date_range(
+    location_key: String,
+    from_date: DateTime,
+    to_date: DateTime,
+) -> Result<Vec<EventUpdateHistoryCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventUpdateHistoryCollection>("event_update_history");
+    let query = doc! {
+        "location_key": location_key,
+        "event_date": { "$gte": from_date, "$lte": to_date }
+    };
+    let results = col.find(query, None)?.flatten().collect();
+    return Ok(results);
+}
+
+pub fn get_event_update_history_list(
+    location_key: String,
+) -> Result<Vec<EventUpdateHistoryCollection>, Box<dyn Error>> {
+    let col = mongodb_client::get_mongodb_db_connection()?
+        .collection::<EventUpdateHistoryCollection>("event_update_history");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "event_date": 1 })
+        .build();
+    let results = col
+        .find(doc! { "location_key": location_key }, find_options)?
+        .flatten()
+        .collect();
     return Ok(results);
 }
 
 pub fn set_init_event_update_history(
     location_key: String,
-    target_date: DateTime<Tz>,
+    target_date: ChronoDateTime<Tz>,
     register_date: i32,
 ) -> Result<Vec<EventUpdateHistoryCollection>, Box<dyn Error>> {
     // insertの元データ
@@ -39,8 +114,8 @@ pub fn set_init_event_update_history(
             let insert_date = target_date + Duration::days(d.into());
             return EventUpdateHistoryCollection {
                 location_key: location_key.clone(),
-                event_date: date_util::format_jst_date(insert_date, "%Y-%m-%d"),
-                update_time: 1.into(),
+                event_date: date_util::to_bson_date_time(insert_date),
+                update_time: None,
             };
         })
         .collect();
@@ -54,8 +129,8 @@ pub fn set_init_event_update_history(
 
 pub fn update_time_event_update_history(
     location_key: String,
-    event_date: String,
-    update_time: i64,
+    event_date: DateTime,
+    update_time: DateTime,
 ) -> Result<(), Box<dyn Error>> {
     let col = mongodb_client::get_mongodb_db_connection()?
         .collection::<EventUpdateHistoryCollection>("event_update_history");
@@ -73,7 +148,7 @@ pub fn update_time_event_update_history(
 
 pub fn delete_event_update_history(
     location_key: String,
-    event_dates: Vec<String>,
+    event_dates: Vec<DateTime>,
 ) -> Result<(), Box<dyn Error>> {
     let col = mongodb_client::get_mongodb_db_connection()?
         .collection::<EventUpdateHistoryCollection>("event_update_history");
@@ -0,0 +1,20 @@
+use crate::model::db::spam_rule_collection::SpamRuleCollection;
+use crate::repository::mongodb_client;
+use mongodb::bson::doc;
+use mongodb::options::ReplaceOptions;
+use std::error::Error;
+
+pub fn get_spam_rule(site_id: String) -> Result<Option<SpamRuleCollection>, Box<dyn Error>> {
+    let col =
+        mongodb_client::get_mongodb_db_connection()?.collection::<SpamRuleCollection>("spam_rule");
+    let result = col.find_one(doc! { "_id": site_id }, None)?;
+    return Ok(result);
+}
+
+pub fn save_spam_rule(spam_rule: SpamRuleCollection) -> Result<(), Box<dyn Error>> {
+    let col =
+        mongodb_client::get_mongodb_db_connection()?.collection::<SpamRuleCollection>("spam_rule");
+    let options = ReplaceOptions::builder().upsert(true).build();
+    col.replace_one(doc! { "_id": spam_rule._id.clone() }, spam_rule, options)?;
+    return Ok(());
+}
@@ -0,0 +1,52 @@
+use crate::model::api::account_user_response::AccountUserResponse;
+use crate::model::db::account_user_collection::AccountUserCollection;
+use crate::repository::account_user_repository;
+use crate::service::auth::{google_auth_service, jwt_service};
+use crate::util::date_util;
+use actix_web::HttpRequest;
+use std::env;
+use std::error::Error;
+use uuid::Uuid;
+
+// リクエストのAuthorizationヘッダーを複合化してユーザidを取得
+pub fn get_account_id_from_authorization_header(req: &HttpRequest) -> Option<String> {
+    let jwt_secret = env::var("JWT_SECRET").ok()?;
+    let auth_header = req
+        .headers()
+        .get(actix_web::http::header::AUTHORIZATION)?
+        .to_str()
+        .ok()?;
+    return auth_header
+        .strip_prefix("Bearer ")
+        .and_then(|t| jwt_service::decode_jwt(t, &jwt_secret).ok())
+        .map(|claim| claim.claims.contents);
+}
+
+// googleの認可コードでログイン（未登録の場合はユーザを登録）
+pub async fn login_by_google_auth_code(
+    auth_code: String,
+) -> Result<AccountUserResponse, Box<dyn Error>> {
+    let gmail = google_auth_service::get_gmail_from_google_auth_code(auth_code).await?;
+    let account_user = match account_user_repository::get_account_user_by_gmail(gmail.clone())? {
+        Some(u) => u,
+        None => {
+            let new_user = AccountUserCollection {
+                _id: Uuid::new_v4().to_string(),
+                gmail,
+                create_time: date_util::to_bson_date_time(date_util::get_now_jst_date_time()),
+            };
+            account_user_repository::add_account_user(new_user.clone())?;
+            new_user
+        }
+    };
+    // account_idをトークンにして返す
+    let auth_token = jwt_service::make_jwt(
+        &env::var("JWT_SECRET")?,
+        &account_user._id,
+        jwt_service::STORE_TOKEN_EXP_HOURS,
+    );
+    return Ok(AccountUserResponse {
+        auth_token,
+        gmail: account_user.gmail,
+    });
+}
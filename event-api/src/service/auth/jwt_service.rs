@@ -0,0 +1,50 @@
+use chrono::Utc;
+use jsonwebtoken::{
+    decode, encode, Algorithm, DecodingKey, EncodingKey, Header, TokenData, Validation,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+pub const STORE_TOKEN_EXP_HOURS: u64 = 4320;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub contents: String,
+    iat: i64,
+    exp: i64,
+}
+
+pub fn make_jwt(secret: &str, contents: &str, exp_hours: u64) -> String {
+    let header = Header {
+        typ: Some("JWT".to_string()),
+        alg: Algorithm::HS256,
+        ..Default::default()
+    };
+
+    let now = Utc::now();
+    let iat = now.timestamp();
+    let exp = (now + Duration::from_secs(exp_hours * 60 * 60)).timestamp();
+    let my_claims = Claims {
+        contents: contents.to_string(),
+        iat,
+        exp,
+    };
+
+    encode(
+        &header,
+        &my_claims,
+        &EncodingKey::from_secret(secret.as_ref()),
+    )
+    .unwrap()
+}
+
+pub fn decode_jwt(
+    jwt: &str,
+    secret: &str,
+) -> Result<TokenData<Claims>, jsonwebtoken::errors::Error> {
+    decode::<Claims>(
+        jwt,
+        &DecodingKey::from_secret(secret.as_ref()),
+        &Validation::default(),
+    )
+}
@@ -0,0 +1,74 @@
+use crate::model::api::event_archive_stats_response::{
+    EventArchiveSiteTrendResponse, EventArchiveStatsCount, EventArchiveStatsResponse,
+};
+use crate::repository::event_archive_repository;
+use crate::util::date_util;
+use mongodb::bson::{doc, Document};
+use std::error::Error;
+
+// 集計対象の検索条件
+fn make_archive_match_query(
+    location_key: Option<String>,
+    from_date: String,
+    to_date: String,
+) -> Result<Document, Box<dyn Error>> {
+    let from_date = date_util::parse_str_bson_date(from_date)?;
+    let to_date = date_util::parse_str_bson_date(to_date)?;
+    let mut query = doc! { "event_date": { "$gte": from_date, "$lte": to_date } };
+    if let Some(l) = location_key {
+        query.insert("location_key", l);
+    }
+    return Ok(query);
+}
+
+fn to_stats_count(counts: Vec<(String, i64)>, sort_by_count: bool) -> Vec<EventArchiveStatsCount> {
+    let mut results: Vec<EventArchiveStatsCount> = counts
+        .into_iter()
+        .map(|(key, count)| EventArchiveStatsCount { key, count })
+        .collect();
+    if sort_by_count {
+        results.sort_by_key(|c| std::cmp::Reverse(c.count));
+    }
+    return results;
+}
+
+pub async fn get_event_archive_stats(
+    location_key: Option<String>,
+    from_date: String,
+    to_date: String,
+) -> Result<EventArchiveStatsResponse, Box<dyn Error>> {
+    let query = make_archive_match_query(location_key, from_date, to_date)?;
+    let sites =
+        event_archive_repository::count_event_archive_by_field(query.clone(), "site_id", false)?;
+    let weekdays = event_archive_repository::count_event_archive_by_field(
+        query.clone(),
+        "event_weekday",
+        false,
+    )?;
+    let hours =
+        event_archive_repository::count_event_archive_by_field(query.clone(), "event_hour", false)?;
+    let tags = event_archive_repository::count_event_archive_by_field(query, "tags", true)?;
+    return Ok(EventArchiveStatsResponse {
+        sites: to_stats_count(sites, true),
+        weekdays: to_stats_count(weekdays, false),
+        hours: to_stats_count(hours, false),
+        tags: to_stats_count(tags, true),
+    });
+}
+
+pub async fn get_event_archive_site_trend(
+    location_key: Option<String>,
+    from_date: String,
+    to_date: String,
+) -> Result<Vec<EventArchiveSiteTrendResponse>, Box<dyn Error>> {
+    let query = make_archive_match_query(location_key, from_date, to_date)?;
+    let results = event_archive_repository::count_event_archive_by_site_month(query)?
+        .into_iter()
+        .map(|c| EventArchiveSiteTrendResponse {
+            site_id: c.site_id,
+            month: c.month,
+            count: c.count,
+        })
+        .collect();
+    return Ok(results);
+}
@@ -0,0 +1,66 @@
+use crate::model::api::event_bookmark_request::{EventBookmarkDeleteRequest, EventBookmarkRequest};
+use crate::model::api::event_bookmark_response::EventBookmarkResponse;
+use crate::model::db::event_bookmark_collection::EventBookmarkCollection;
+use crate::repository::event_bookmark_repository;
+use crate::repository::event_repository;
+use crate::util::date_util;
+use std::error::Error;
+use uuid::Uuid;
+
+pub async fn get_bookmark_list(
+    account_id: String,
+    going_only: bool,
+) -> Result<Vec<EventBookmarkResponse>, Box<dyn Error>> {
+    let results = event_bookmark_repository::get_bookmarks(account_id, going_only)?
+        .into_iter()
+        .map(EventBookmarkResponse::from_collection)
+        .collect();
+    return Ok(results);
+}
+
+// ブックマークの登録（登録済みの場合は参加予定のみ更新）
+pub async fn save_bookmark(
+    account_id: String,
+    request: EventBookmarkRequest,
+) -> Result<EventBookmarkResponse, Box<dyn Error>> {
+    if let Some(mut bookmark) = event_bookmark_repository::get_bookmark(
+        account_id.clone(),
+        request.site_id.clone(),
+        request.site_event_id.clone(),
+    )? {
+        event_bookmark_repository::update_bookmark_going(bookmark._id.clone(), request.going)?;
+        bookmark.going = request.going;
+        return Ok(EventBookmarkResponse::from_collection(bookmark));
+    }
+    // ブックマーク時点のイベント情報を保持
+    let event =
+        match event_repository::get_event_by_site_event_id(request.site_id, request.site_event_id)?
+        {
+            Some(e) => e,
+            None => return Err("Event not found".into()),
+        };
+    let bookmark = EventBookmarkCollection::from_event(
+        Uuid::new_v4().to_string(),
+        account_id,
+        event,
+        request.going,
+        date_util::to_bson_date_time(date_util::get_now_jst_date_time()),
+    );
+    event_bookmark_repository::add_bookmark(bookmark.clone())?;
+    return Ok(EventBookmarkResponse::from_collection(bookmark));
+}
+
+pub async fn delete_bookmark(
+    account_id: String,
+    request: EventBookmarkDeleteRequest,
+) -> Result<(), Box<dyn Error>> {
+    let count = event_bookmark_repository::delete_bookmark(
+        account_id,
+        request.site_id,
+        request.site_event_id,
+    )?;
+    if count == 0 {
+        return Err("Bookmark not found".into());
+    }
+    return Ok(());
+}
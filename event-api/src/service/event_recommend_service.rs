@@ -0,0 +1,137 @@
+use crate::model::api::event_recommend_request::EventClickRequest;
+use crate::model::api::event_recommend_response::EventRecommendResponse;
+use crate::model::api::event_response::EventResponse;
+use crate::model::db::event_click_collection::EventClickCollection;
+use crate::model::db::event_collection::EventCollection;
+use crate::repository::event_bookmark_repository;
+use crate::repository::event_click_repository;
+use crate::repository::event_repository;
+use crate::util::date_util;
+use crate::util::similarity_util;
+use std::collections::HashSet;
+use std::error::Error;
+use uuid::Uuid;
+
+// この類似度未満のイベントは除外
+const MIN_SIMILARITY: f64 = 0.2;
+// おすすめの算出に使うクリック履歴の件数
+const CLICK_HISTORY_LIMIT: i64 = 100;
+
+// 類似度の高い順に並べて上位を返す
+fn rank_events(
+    mut events: Vec<EventCollection>,
+    limit: usize,
+    calc_score: impl Fn(&EventCollection) -> f64,
+) -> Vec<EventRecommendResponse> {
+    // 複数日・複数地域に登録された同じイベントは直近の1件のみ
+    events.sort_by_key(|e| e.event_date);
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    let mut results: Vec<EventRecommendResponse> = events
+        .into_iter()
+        .filter(|e| seen.insert((e.site_id.clone(), e.site_event_id.clone())))
+        .map(|e| (calc_score(&e), e))
+        .filter(|(score, _)| *score >= MIN_SIMILARITY)
+        .map(|(score, e)| EventRecommendResponse {
+            event: EventResponse::from_collection(e),
+            score,
+        })
+        .collect();
+    results.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.event.event_date.cmp(&b.event.event_date))
+    });
+    results.truncate(limit);
+    return results;
+}
+
+// 同じ地域の今後のイベントからタイトルが似ているもの
+pub async fn get_similar_events(
+    site_id: String,
+    site_event_id: String,
+    limit: usize,
+) -> Result<Vec<EventRecommendResponse>, Box<dyn Error>> {
+    let base_event = match event_repository::get_event_by_site_event_id(site_id, site_event_id)? {
+        Some(e) => e,
+        None => return Err("Event not found".into()),
+    };
+    let base_ngrams = similarity_util::get_title_ngrams(&base_event.title);
+    let events = event_repository::get_upcoming_events(
+        vec![base_event.location_key.clone()],
+        date_util::to_bson_date_time(date_util::get_now_jst_date()),
+    )?
+    .into_iter()
+    .filter(|e| !(e.site_id == base_event.site_id && e.site_event_id == base_event.site_event_id))
+    .collect();
+    let results = rank_events(events, limit, |e| {
+        similarity_util::calc_similarity(&base_ngrams, &similarity_util::get_title_ngrams(&e.title))
+    });
+    return Ok(results);
+}
+
+// ブックマーク・クリック履歴に似た今後のイベント
+pub async fn get_recommend_events(
+    account_id: String,
+    limit: usize,
+) -> Result<Vec<EventRecommendResponse>, Box<dyn Error>> {
+    let bookmarks = event_bookmark_repository::get_bookmarks(account_id.clone(), false)?;
+    let clicks = event_click_repository::get_recent_event_clicks(account_id, CLICK_HISTORY_LIMIT)?;
+    let history: Vec<(String, String)> = bookmarks
+        .iter()
+        .map(|b| (b.location_key.clone(), b.title.clone()))
+        .chain(
+            clicks
+                .iter()
+                .map(|c| (c.location_key.clone(), c.title.clone())),
+        )
+        .collect();
+    if history.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut location_keys: Vec<String> = history.iter().map(|(l, _)| l.clone()).collect();
+    location_keys.sort();
+    location_keys.dedup();
+    let history_ngrams: Vec<HashSet<String>> = history
+        .iter()
+        .map(|(_, title)| similarity_util::get_title_ngrams(title))
+        .collect();
+    // ブックマーク済みのイベントは除外
+    let bookmarked: HashSet<(String, String)> = bookmarks
+        .into_iter()
+        .map(|b| (b.site_id, b.site_event_id))
+        .collect();
+    let events = event_repository::get_upcoming_events(
+        location_keys,
+        date_util::to_bson_date_time(date_util::get_now_jst_date()),
+    )?
+    .into_iter()
+    .filter(|e| !bookmarked.contains(&(e.site_id.clone(), e.site_event_id.clone())))
+    .collect();
+    let results = rank_events(events, limit, |e| {
+        let ngrams = similarity_util::get_title_ngrams(&e.title);
+        history_ngrams
+            .iter()
+            .map(|h| similarity_util::calc_similarity(h, &ngrams))
+            .fold(0.0, f64::max)
+    });
+    return Ok(results);
+}
+
+pub async fn add_event_click(
+    account_id: String,
+    request: EventClickRequest,
+) -> Result<(), Box<dyn Error>> {
+    let event =
+        match event_repository::get_event_by_site_event_id(request.site_id, request.site_event_id)?
+        {
+            Some(e) => e,
+            None => return Err("Event not found".into()),
+        };
+    event_click_repository::add_event_click(EventClickCollection::from_event(
+        Uuid::new_v4().to_string(),
+        account_id,
+        event,
+        date_util::to_bson_date_time(date_util::get_now_jst_date_time()),
+    ))?;
+    return Ok(());
+}
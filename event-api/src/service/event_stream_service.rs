@@ -0,0 +1,87 @@
+use crate::model::api::event_response::EventResponse;
+use crate::model::api::event_stream_message::EventStreamMessage;
+use crate::model::db::event_collection::EventCollection;
+use actix_web::web::Bytes;
+use futures::stream::{self, Stream};
+use std::time::Duration;
+use tokio::sync::broadcast;
+use tokio::time::{self, Instant};
+
+// 受信が遅れたクライアント向けに保持するメッセージ数
+const STREAM_CHANNEL_CAPACITY: usize = 256;
+// プロキシにアイドル切断されないよう送るコメントの間隔
+const KEEPALIVE_INTERVAL_SECS: u64 = 15;
+
+#[derive(Clone)]
+pub struct EventStreamBroadcaster {
+    sender: broadcast::Sender<EventStreamMessage>,
+}
+
+impl EventStreamBroadcaster {
+    pub fn new() -> EventStreamBroadcaster {
+        let (sender, _) = broadcast::channel(STREAM_CHANNEL_CAPACITY);
+        return EventStreamBroadcaster { sender };
+    }
+
+    // 購読者がいない場合の送信エラーは無視
+    pub fn send_progress(&self, location_key: &str, message: String) {
+        let _ = self.sender.send(EventStreamMessage::Progress {
+            location_key: location_key.to_string(),
+            message,
+        });
+    }
+
+    pub fn send_events(&self, location_key: &str, event_date: &str, events: &[EventCollection]) {
+        // サイトごとに送信
+        let mut site_ids: Vec<&str> = events.iter().map(|e| e.site_id.as_str()).collect();
+        site_ids.sort_unstable();
+        site_ids.dedup();
+        for site_id in site_ids {
+            let _ = self.sender.send(EventStreamMessage::Events {
+                location_key: location_key.to_string(),
+                event_date: event_date.to_string(),
+                site_id: site_id.to_string(),
+                events: events
+                    .iter()
+                    .filter(|e| e.site_id == site_id)
+                    .cloned()
+                    .map(EventResponse::from_collection)
+                    .collect(),
+            });
+        }
+    }
+
+    // 指定した地域のメッセージをSSEの形式で返すStream
+    pub fn subscribe_sse(
+        &self,
+        location_key: String,
+    ) -> impl Stream<Item = Result<Bytes, actix_web::Error>> {
+        let receiver = self.sender.subscribe();
+        let events = stream::unfold(receiver, move |mut rx| {
+            let location_key = location_key.clone();
+            async move {
+                loop {
+                    match rx.recv().await {
+                        Ok(message) if message.location_key() == location_key => {
+                            let data = serde_json::to_string(&message).unwrap_or_default();
+                            let sse =
+                                format!("event: {}\ndata: {}\n\n", message.event_name(), data);
+                            return Some((Ok(Bytes::from(sse)), rx));
+                        }
+                        Ok(_) => continue,
+                        // 取りこぼしたメッセージは読み飛ばす
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            }
+        });
+        let period = Duration::from_secs(KEEPALIVE_INTERVAL_SECS);
+        let interval = time::interval_at(Instant::now() + period, period);
+        let keepalive = stream::unfold(interval, |mut interval| async move {
+            interval.tick().await;
+            return Some((Ok(Bytes::from_static(b":keepalive\n\n")), interval));
+        });
+        return stream::select(events, keepalive);
+    }
+}
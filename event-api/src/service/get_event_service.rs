@@ -1,45 +1,163 @@
+use crate::model::api::event_calendar_response::{
+    EventCalendarDayResponse, EventCalendarResponse, EventCalendarSiteCountResponse,
+};
 use crate::model::api::event_info_master_response::{
-    EventInfoMasterResponse, EventInfoMasterResponseKV,
+    EventInfoMasterResponse, EventInfoMasterResponseKV, EventInfoMasterResponseLocation,
 };
-use crate::model::db::event_collection::EventCollection;
+use crate::model::api::event_page_response::EventPageResponse;
+use crate::model::api::event_response::EventResponse;
+use crate::model::api::event_update_status_response::EventUpdateStatusResponse;
 use crate::repository::event_repository;
+use crate::repository::event_search_info_repository;
+use crate::util::date_util;
 use std::error::Error;
 
-pub fn get_event_info_master() -> EventInfoMasterResponse {
-    return EventInfoMasterResponse {
-        locations: vec![EventInfoMasterResponseKV {
-            key: "tokyo".to_string(),
-            label: "東京".to_string(),
-        }],
-        sites: vec![
-            EventInfoMasterResponseKV {
-                key: "tunagate".to_string(),
-                label: "つなげーと".to_string(),
-            },
-            EventInfoMasterResponseKV {
-                key: "jmty".to_string(),
-                label: "ジモティー".to_string(),
-            },
-            EventInfoMasterResponseKV {
-                key: "koryupa".to_string(),
-                label: "コリュパ".to_string(),
-            },
-            EventInfoMasterResponseKV {
-                key: "kokuchpro".to_string(),
-                label: "こくちーずプロ".to_string(),
-            },
-            EventInfoMasterResponseKV {
-                key: "twipla".to_string(),
-                label: "TwiPla".to_string(),
-            },
-        ],
-    };
+// サイトのキーと表示名
+const SITE_LABELS: [(&str, &str); 5] = [
+    ("tunagate", "つなげーと"),
+    ("jmty", "ジモティー"),
+    ("koryupa", "コリュパ"),
+    ("kokuchpro", "こくちーずプロ"),
+    ("twipla", "TwiPla"),
+];
+
+pub fn get_event_info_master() -> Result<EventInfoMasterResponse, Box<dyn Error>> {
+    let event_search_master_col = event_search_info_repository::get_event_search_master()?;
+    let locations: Vec<EventInfoMasterResponseLocation> = event_search_master_col
+        .iter()
+        .map(|m| EventInfoMasterResponseLocation {
+            key: m._id.clone(),
+            label: m.label.clone().unwrap_or_else(|| m._id.clone()),
+            sites: m
+                .get_enabled_sites()
+                .into_iter()
+                .map(|s| s.site_id)
+                .collect(),
+        })
+        .collect();
+    // いずれかの地域で収集対象になっているサイトのみ
+    let sites = SITE_LABELS
+        .iter()
+        .filter(|(key, _)| locations.iter().any(|l| l.sites.iter().any(|s| s == key)))
+        .map(|(key, label)| EventInfoMasterResponseKV {
+            key: key.to_string(),
+            label: label.to_string(),
+        })
+        .collect();
+    return Ok(EventInfoMasterResponse { locations, sites });
 }
 
 pub async fn get_event_info_list(
     location_key: String,
     event_date: String,
-) -> Result<Vec<EventCollection>, Box<dyn Error>> {
-    let results = event_repository::get_events(location_key, event_date)?;
+    include_flagged: bool,
+) -> Result<Vec<EventResponse>, Box<dyn Error>> {
+    let event_date = date_util::parse_str_bson_date(event_date)?;
+    let results = event_repository::get_events(location_key, event_date, include_flagged)?
+        .into_iter()
+        .map(EventResponse::from_collection)
+        .collect();
     return Ok(results);
 }
+
+// 条件を指定したイベント一覧（ページ単位）
+pub async fn get_event_page(
+    location_key: String,
+    event_date: String,
+    include_flagged: bool,
+    site_ids: Option<Vec<String>>,
+    keyword: Option<String>,
+    offset: u64,
+    limit: i64,
+) -> Result<EventPageResponse, Box<dyn Error>> {
+    let event_date = date_util::parse_str_bson_date(event_date)?;
+    let keyword = keyword.filter(|k| !k.is_empty());
+    let (events, total_count) = event_repository::search_events(
+        location_key,
+        event_date,
+        include_flagged,
+        site_ids,
+        keyword,
+        offset,
+        limit,
+    )?;
+    return Ok(EventPageResponse {
+        total_count,
+        events: events
+            .into_iter()
+            .map(EventResponse::from_collection)
+            .collect(),
+    });
+}
+
+pub fn get_update_status(
+    location_key: String,
+) -> Result<Vec<EventUpdateStatusResponse>, Box<dyn Error>> {
+    let histories = event_search_info_repository::get_event_update_history_list(location_key)?;
+    let results = histories
+        .into_iter()
+        .map(|h| EventUpdateStatusResponse {
+            event_date: date_util::format_bson_date(h.event_date, "%Y-%m-%d"),
+            gathered: h.update_time.is_some(),
+            update_time: h
+                .update_time
+                .map(|t| date_util::format_bson_date(t, "%Y-%m-%d %H:%M:%S")),
+        })
+        .collect();
+    return Ok(results);
+}
+
+pub async fn get_event_calendar(
+    location_key: String,
+    month: String,
+) -> Result<EventCalendarResponse, Box<dyn Error>> {
+    let month_dates = date_util::get_month_str_dates(month.clone())?;
+    let (from_date, to_date) = match (month_dates.first(), month_dates.last()) {
+        (Some(f), Some(t)) => (
+            date_util::parse_str_bson_date(f.clone())?,
+            date_util::parse_str_bson_date(t.clone())?,
+        ),
+        (_, _) => return Err("Invalid month".into()),
+    };
+    // 日付・サイトごとの件数と収集状況
+    let counts =
+        event_repository::count_events_by_date_site(location_key.clone(), from_date, to_date)?;
+    let histories = event_search_info_repository::get_event_update_history_by_date_range(
+        location_key.clone(),
+        from_date,
+        to_date,
+    )?;
+    let days = month_dates
+        .into_iter()
+        .map(|event_date| {
+            let gather_status = match histories
+                .iter()
+                .find(|h| date_util::format_bson_date(h.event_date, "%Y-%m-%d") == event_date)
+            {
+                Some(h) if h.update_time.is_some() => "gathered",
+                Some(_) => "not_gathered",
+                None => "out_of_range",
+            };
+            let mut site_counts: Vec<EventCalendarSiteCountResponse> = counts
+                .iter()
+                .filter(|c| c.event_date == event_date)
+                .map(|c| EventCalendarSiteCountResponse {
+                    site_id: c.site_id.clone(),
+                    count: c.count,
+                })
+                .collect();
+            site_counts.sort_by(|a, b| a.site_id.cmp(&b.site_id));
+            EventCalendarDayResponse {
+                event_date,
+                gather_status: gather_status.to_string(),
+                total_count: site_counts.iter().map(|c| c.count).sum(),
+                site_counts,
+            }
+        })
+        .collect();
+    return Ok(EventCalendarResponse {
+        location_key,
+        month,
+        days,
+    });
+}
@@ -0,0 +1,54 @@
+use crate::gather::gather_event_data;
+use crate::model::api::event_response::EventResponse;
+use crate::model::api::manual_event_request::{ManualEventModerateRequest, ManualEventRequest};
+use crate::repository::event_repository;
+use crate::repository::event_search_info_repository;
+use crate::util::date_util;
+use std::error::Error;
+
+pub async fn add_manual_event(
+    request: ManualEventRequest,
+) -> Result<EventResponse, Box<dyn Error>> {
+    // 登録されている地域か
+    let event_search_master_col = event_search_info_repository::get_event_search_master()?;
+    if !event_search_master_col
+        .iter()
+        .any(|m| m._id == request.location_key)
+    {
+        return Err("Unknown location_key".into());
+    }
+    // 同じURLが登録済みか
+    if event_repository::get_manual_event(request.location_key.clone(), request.url.clone())?
+        .is_some()
+    {
+        return Err("Already registered url".into());
+    }
+    // ページからイベント情報を取得して承認待ちで登録
+    let now_time = date_util::to_bson_date_time(date_util::get_now_jst_date_time());
+    let event = gather_event_data::get_manual_event_data(request, now_time).await?;
+    event_repository::add_events(vec![event.clone()])?;
+    return Ok(EventResponse::from_collection(event));
+}
+
+pub async fn get_pending_manual_events() -> Result<Vec<EventResponse>, Box<dyn Error>> {
+    let results = event_repository::get_pending_manual_events()?
+        .into_iter()
+        .map(EventResponse::from_collection)
+        .collect();
+    return Ok(results);
+}
+
+pub async fn moderate_manual_event(
+    request: ManualEventModerateRequest,
+) -> Result<(), Box<dyn Error>> {
+    // 承認なら公開、否認なら削除
+    let count = if request.approved {
+        event_repository::approve_manual_event(request.location_key, request.site_event_id)?
+    } else {
+        event_repository::delete_manual_event(request.location_key, request.site_event_id)?
+    };
+    if count == 0 {
+        return Err("Manual event not found".into());
+    }
+    return Ok(());
+}
@@ -0,0 +1,125 @@
+use crate::model::api::spam_rule_request::SpamRuleRequest;
+use crate::model::db::event_collection::{EventCollection, EventSpamInfo};
+use crate::model::db::spam_rule_collection::SpamRuleCollection;
+use crate::repository::event_repository;
+use crate::repository::spam_rule_repository;
+use crate::util::date_util;
+use mongodb::bson::DateTime;
+use std::collections::BTreeMap;
+use std::error::Error;
+
+// スパム判定の対象サイト
+pub const SPAM_FILTER_SITE_ID: &str = "jmty";
+
+pub fn get_spam_rule() -> Result<SpamRuleCollection, Box<dyn Error>> {
+    let rule = spam_rule_repository::get_spam_rule(SPAM_FILTER_SITE_ID.to_string())?
+        .unwrap_or_else(|| SpamRuleCollection::default_rule(SPAM_FILTER_SITE_ID));
+    return Ok(rule);
+}
+
+// 収集したイベントにスパム判定を付与
+pub fn apply_spam_filter(
+    events: &mut [EventCollection],
+    location_key: String,
+    event_date: DateTime,
+) -> Result<(), Box<dyn Error>> {
+    if !events.iter().any(|e| e.site_id == SPAM_FILTER_SITE_ID) {
+        return Ok(());
+    }
+    let rule = get_spam_rule()?;
+    let patterns = rule.compile_title_patterns()?;
+    // 収集中の日付以外での投稿日数
+    let poster_date_counts = event_repository::count_dates_by_poster(
+        SPAM_FILTER_SITE_ID.to_string(),
+        location_key,
+        Some(event_date),
+    )?;
+    for event in events
+        .iter_mut()
+        .filter(|e| e.site_id == SPAM_FILTER_SITE_ID)
+    {
+        let poster_date_count = match &event.poster_id {
+            Some(p) => poster_date_counts.get(p).copied().unwrap_or(0) + 1,
+            None => 0,
+        };
+        let score = rule.calc_score(&patterns, &event.title, poster_date_count);
+        event.spam = Some(EventSpamInfo {
+            score,
+            flagged: score >= rule.flag_threshold,
+        });
+    }
+    return Ok(());
+}
+
+// 閾値が0以下だと全件がスパム判定になるため1以上、スコアは0以上
+fn validate_spam_rule_request(request: &SpamRuleRequest) -> Result<(), Box<dyn Error>> {
+    if request.flag_threshold < 1 {
+        return Err("flag_threshold must be 1 or more".into());
+    }
+    if request.keyword_score < 0 || request.pattern_score < 0 || request.poster_repeat_score < 0 {
+        return Err("Score must not be negative".into());
+    }
+    if request.poster_repeat_date_count < 1 {
+        return Err("poster_repeat_date_count must be 1 or more".into());
+    }
+    return Ok(());
+}
+
+// ルールを更新して登録済みのイベントを再判定
+pub async fn update_spam_rule(
+    request: SpamRuleRequest,
+) -> Result<SpamRuleCollection, Box<dyn Error>> {
+    validate_spam_rule_request(&request)?;
+    let rule = SpamRuleCollection {
+        _id: SPAM_FILTER_SITE_ID.to_string(),
+        blocklist_keywords: request.blocklist_keywords,
+        blocklist_title_patterns: request.blocklist_title_patterns,
+        keyword_score: request.keyword_score,
+        pattern_score: request.pattern_score,
+        poster_repeat_date_count: request.poster_repeat_date_count,
+        poster_repeat_score: request.poster_repeat_score,
+        flag_threshold: request.flag_threshold,
+        update_time: Some(date_util::to_bson_date_time(
+            date_util::get_now_jst_date_time(),
+        )),
+    };
+    let patterns = rule.compile_title_patterns()?;
+    spam_rule_repository::save_spam_rule(rule.clone())?;
+
+    let events = event_repository::get_site_events(SPAM_FILTER_SITE_ID.to_string())?;
+    let mut location_keys: Vec<String> = events.iter().map(|e| e.location_key.clone()).collect();
+    location_keys.sort();
+    location_keys.dedup();
+    for location_key in location_keys {
+        let poster_date_counts = event_repository::count_dates_by_poster(
+            SPAM_FILTER_SITE_ID.to_string(),
+            location_key.clone(),
+            None,
+        )?;
+        // スコアごとにまとめて更新
+        let mut score_events: BTreeMap<i32, Vec<(String, DateTime)>> = BTreeMap::new();
+        for event in events.iter().filter(|e| e.location_key == location_key) {
+            let poster_date_count = match &event.poster_id {
+                Some(p) => poster_date_counts.get(p).copied().unwrap_or(0),
+                None => 0,
+            };
+            let score = rule.calc_score(&patterns, &event.title, poster_date_count);
+            score_events
+                .entry(score)
+                .or_default()
+                .push((event.site_event_id.clone(), event.event_date));
+        }
+        for (score, event_keys) in score_events {
+            event_repository::update_events_spam(
+                SPAM_FILTER_SITE_ID.to_string(),
+                location_key.clone(),
+                event_keys,
+                EventSpamInfo {
+                    score,
+                    flagged: score >= rule.flag_threshold,
+                },
+            )?;
+        }
+    }
+    return Ok(rule);
+}
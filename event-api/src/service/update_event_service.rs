@@ -1,113 +1,161 @@
 use crate::gather::gather_event_data;
-use crate::model::db::event_info_collection::EventUpdateHistoryCollection;
+use crate::model::db::event_archive_collection::EventArchiveCollection;
+use crate::model::db::event_collection::EventCollection;
+use crate::repository::event_archive_repository;
 use crate::repository::event_repository;
 use crate::repository::event_search_info_repository;
+use crate::service::event_stream_service::EventStreamBroadcaster;
+use crate::service::spam_filter_service;
 use crate::util::date_util;
+use chrono::Duration;
+use futures::StreamExt;
+use mongodb::bson::DateTime;
 use std::error::Error;
 
-pub async fn update_event_execute() -> Result<(), Box<dyn Error>> {
+// 1サイト分の収集結果をスパム判定して登録し、配信
+fn commit_site_events(
+    broadcaster: &EventStreamBroadcaster,
+    location_key: &str,
+    event_date: DateTime,
+    event_date_str: &str,
+    mut site_events: Vec<EventCollection>,
+) -> Result<(), Box<dyn Error>> {
+    spam_filter_service::apply_spam_filter(&mut site_events, location_key.to_string(), event_date)?;
+    event_repository::add_events(site_events.clone())?;
+    // スパム判定されたものは配信しない
+    let publish_events: Vec<EventCollection> = site_events
+        .into_iter()
+        .filter(|e| !e.spam.as_ref().is_some_and(|s| s.flagged))
+        .collect();
+    broadcaster.send_events(location_key, event_date_str, &publish_events);
+    return Ok(());
+}
+
+pub async fn update_event_execute(
+    broadcaster: &EventStreamBroadcaster,
+) -> Result<(), Box<dyn Error>> {
     // DBから更新の管理情報を取得
     let event_search_master_col = event_search_info_repository::get_event_search_master()?;
-    let event_update_history_col = event_search_info_repository::get_event_update_history()?;
     // 現在日付（0時0分0秒）
     let now_date = date_util::get_now_jst_date();
-    let now_date_time = now_date.timestamp();
+    let now_date_time = date_util::to_bson_date_time(now_date);
+    // 現在時刻から2日以内の更新は対象外
+    let now_time = date_util::get_now_jst_date_time();
+    let updated_before = date_util::to_bson_date_time(now_time - Duration::days(2));
+    // アーカイブ日時
+    let archive_time = date_util::to_bson_date_time(now_time);
 
     for event_search_master in event_search_master_col.into_iter() {
         let event_search_master_ref = &event_search_master;
         let location_key = event_search_master_ref.clone()._id;
-        // 該当の地域キーで更新履歴を絞り込み
-        let event_updates = event_update_history_col
-            .iter()
-            .filter(|history| history.location_key == location_key);
-        let event_updates_clone = event_updates.clone().map(|e| e.clone());
-        let mut event_updates_vec = event_updates_clone.collect();
-        // 更新履歴が登録済みで無い場合は初期値で上書き
-        if event_updates.count() == 0 {
+        // 更新履歴が登録済みで無い場合は初期値を登録
+        if event_search_info_repository::count_event_update_history(location_key.clone())? == 0 {
             // 翌日から7日分初期設定
-            event_updates_vec = event_search_info_repository::set_init_event_update_history(
+            event_search_info_repository::set_init_event_update_history(
                 location_key.clone(),
                 now_date,
                 7,
             )?;
         }
-        let event_updates_vec_refer = &event_updates_vec;
-        // 更新対象
-        let update_targets = event_updates_vec_refer
-            .into_iter()
-            .filter(|history| history.is_update_target(now_date_time));
-        // 2つの日付のデータをサイトから更新
-        for (i, val) in update_targets.into_iter().enumerate() {
-            // 3つ目以上はスキップ
-            if i > 1 {
-                break;
-            } else {
-                // サイトからデータ収集
-                let gather_events = gather_event_data::get_event_data(
-                    event_search_master_ref.clone(),
-                    val.event_date.clone(),
-                    now_date_time.clone(),
-                )
-                .await?;
-                // event_update_historyに登録
-                event_repository::add_events(gather_events)?;
-                // event_update_historyのupdate_timeを現時刻で更新
-                event_search_info_repository::update_time_event_update_history(
-                    location_key.clone(),
-                    val.event_date.clone(),
-                    now_date_time.clone(),
-                )?;
-                // 前に追加したeventデータを削除
+        // 更新対象（2つの日付のデータをサイトから更新）
+        let update_targets = event_search_info_repository::get_update_target_event_update_history(
+            location_key.clone(),
+            now_date_time,
+            updated_before,
+            2,
+        )?;
+        for val in update_targets.iter() {
+            let event_date_str = date_util::format_bson_date(val.event_date, "%Y-%m-%d");
+            // サイトからデータ収集
+            broadcaster.send_progress(&location_key, format!("{} gather start", event_date_str));
+            let mut site_results = gather_event_data::get_event_data(
+                event_search_master_ref.clone(),
+                val.event_date,
+                now_date_time,
+                broadcaster,
+            );
+            // 収集の終わったサイトから登録して配信
+            while let Some(site_result) = site_results.next().await {
+                let result = site_result.and_then(|site_events| {
+                    commit_site_events(
+                        broadcaster,
+                        &location_key,
+                        val.event_date,
+                        &event_date_str,
+                        site_events,
+                    )
+                });
+                if let Err(e) = result {
+                    // 途中まで登録したサイトの分は破棄（前回の収集分を残す）
+                    event_repository::delete_events(
+                        location_key.clone(),
+                        val.event_date,
+                        now_date_time,
+                    )?;
+                    return Err(e);
+                }
+            }
+            // 全サイトの登録後、event_update_historyのupdate_timeを現時刻で更新
+            event_search_info_repository::update_time_event_update_history(
+                location_key.clone(),
+                val.event_date,
+                now_date_time,
+            )?;
+            // 前に追加したeventデータを削除
+            if let Some(prev_update_time) = val.update_time {
                 event_repository::delete_events(
                     location_key.clone(),
-                    val.event_date.clone(),
-                    val.update_time.clone(),
+                    val.event_date,
+                    prev_update_time,
                 )?;
             }
+            broadcaster.send_progress(&location_key, format!("{} done", event_date_str));
         }
-        // 削除対象
-        let delete_targets = event_updates_vec_refer
-            .into_iter()
-            .filter(|history| history.is_delete_target(now_date_time));
-        let delete_targets_clone = delete_targets.clone().map(|e| e.clone());
-        let delete_targets_vec: Vec<EventUpdateHistoryCollection> = delete_targets_clone.collect();
-        let delete_targets_vec_refer = &delete_targets_vec;
-        // 削除処理
-        let delete_count = delete_targets_vec_refer.len();
-        if delete_count > 0 {
-            for (_, delete_target_ref) in delete_targets_vec_refer.into_iter().enumerate() {
-                event_repository::delete_events(
+        // 削除対象（当日より前）
+        let delete_targets = event_search_info_repository::get_delete_target_event_update_history(
+            location_key.clone(),
+            now_date_time,
+        )?;
+        if !delete_targets.is_empty() {
+            for delete_target_ref in delete_targets.iter() {
+                // 削除前にアーカイブへ移動
+                let archive_events = event_repository::get_archive_target_events(
                     location_key.clone(),
-                    delete_target_ref.event_date.clone(),
-                    delete_target_ref.update_time.clone(),
+                    delete_target_ref.event_date,
+                    delete_target_ref.update_time,
                 )?;
+                event_archive_repository::add_event_archives(
+                    archive_events
+                        .into_iter()
+                        .map(|e| EventArchiveCollection::from_event(e, archive_time))
+                        .collect(),
+                )?;
+                if let Some(delete_update_time) = delete_target_ref.update_time {
+                    event_repository::delete_events(
+                        location_key.clone(),
+                        delete_target_ref.event_date,
+                        delete_update_time,
+                    )?;
+                }
             }
+            let delete_dates: Vec<_> = delete_targets.iter().map(|d| d.event_date).collect();
+            // 手動登録のイベントも過去日付になったら削除（承認済みはアーカイブ済み）
+            event_repository::delete_manual_events(location_key.clone(), delete_dates.clone())?;
             event_search_info_repository::delete_event_update_history(
                 location_key.clone(),
-                delete_targets_vec_refer
-                    .iter()
-                    .map(|d| d.event_date.clone())
-                    .collect(),
+                delete_dates,
             )?;
         }
         // 日付の追加（登録されているレコードが14日に満たない場合）
-        let update_count = event_updates_vec_refer.len();
-        if (update_count - delete_count) < 14 {
+        if event_search_info_repository::count_event_update_history(location_key.clone())? < 14 {
             // maxの日付
-            let mut max_date_time = now_date;
-            match event_updates_vec_refer
-                .iter()
-                .map(
-                    |e| match date_util::parse_str_jst_date(e.event_date.clone()) {
-                        Ok(d) => d,
-                        Err(_e) => now_date,
-                    },
-                )
-                .max()
-            {
-                Some(r) => max_date_time = r,
-                None => {}
-            }
+            let max_date_time =
+                match event_search_info_repository::get_max_event_date_event_update_history(
+                    location_key.clone(),
+                )? {
+                    Some(d) if d > now_date_time => date_util::from_bson_date_time(d),
+                    _ => now_date,
+                };
             // 2日分を追加
             event_search_info_repository::set_init_event_update_history(
                 location_key.clone(),
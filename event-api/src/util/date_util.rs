@@ -1,17 +1,15 @@
-use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use chrono_tz::{Asia::Tokyo, Tz};
+use mongodb::bson;
 
 use std::error::Error;
 
-pub const DATE_SEC: i64 = 60 * 60 * 24;
-
 // 日本時間当日を0時0分0秒で返す
 pub fn get_now_jst_date() -> DateTime<Tz> {
-    let utc = Utc::now().naive_utc();
-    let jst = Tokyo.from_utc_datetime(&utc);
+    let jst = get_now_jst_date_time();
     return Tokyo
-        .ymd(jst.year(), jst.month(), jst.day())
-        .and_hms(0, 0, 0);
+        .from_local_datetime(&jst.date_naive().and_time(chrono::NaiveTime::MIN))
+        .unwrap();
 }
 
 // 日本時間当日
@@ -23,13 +21,72 @@ pub fn get_now_jst_date_time() -> DateTime<Tz> {
 // 日付の文字列をDateにパース
 pub fn parse_str_jst_date(jst_str_date: String) -> Result<DateTime<Tz>, Box<dyn Error>> {
     let naive_date = NaiveDate::parse_from_str(&jst_str_date, "%Y-%m-%d")?;
-    let japan_date_time = Tokyo
-        .ymd(naive_date.year(), naive_date.month(), naive_date.day())
-        .and_hms(0, 0, 0);
+    let japan_date_time = match Tokyo
+        .from_local_datetime(&naive_date.and_time(chrono::NaiveTime::MIN))
+        .single()
+    {
+        Some(d) => d,
+        None => return Err("Invalid date".into()),
+    };
     return Ok(japan_date_time);
 }
 
+// 日付の文字列をBSONの日時にパース
+pub fn parse_str_bson_date(jst_str_date: String) -> Result<bson::DateTime, Box<dyn Error>> {
+    return Ok(to_bson_date_time(parse_str_jst_date(jst_str_date)?));
+}
+
+// 日本時間をBSONの日時に変換
+pub fn to_bson_date_time(jst_date_time: DateTime<Tz>) -> bson::DateTime {
+    return bson::DateTime::from_millis(jst_date_time.timestamp_millis());
+}
+
+// BSONの日時を日本時間に変換
+pub fn from_bson_date_time(bson_date_time: bson::DateTime) -> DateTime<Tz> {
+    return Tokyo
+        .timestamp_millis_opt(bson_date_time.timestamp_millis())
+        .unwrap();
+}
+
+// BSONの日時を日本時間の文字列にフォーマット
+pub fn format_bson_date(bson_date_time: bson::DateTime, format_str: &str) -> String {
+    return format_jst_date(from_bson_date_time(bson_date_time), format_str);
+}
+
 // 日付を文字列にフォーマット
 pub fn format_jst_date(jst_date_time: DateTime<Tz>, format_str: &str) -> String {
     return jst_date_time.format(format_str).to_string();
 }
+
+// ISO8601形式の日時文字列を日本時間にパース（時刻を含むかどうかも返す）
+pub fn parse_iso_str_jst_date_time(
+    iso_str_date_time: &str,
+) -> Result<(DateTime<Tz>, bool), Box<dyn Error>> {
+    let trimmed = iso_str_date_time.trim();
+    // タイムゾーン付き
+    if let Ok(d) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok((d.with_timezone(&Tokyo), true));
+    }
+    // タイムゾーン無しは日本時間として扱う
+    for format_str in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(d) = NaiveDateTime::parse_from_str(trimmed, format_str) {
+            if let Some(jst) = Tokyo.from_local_datetime(&d).single() {
+                return Ok((jst, true));
+            }
+        }
+    }
+    // 日付のみ
+    let date_str = trimmed.get(0..10).unwrap_or(trimmed);
+    return Ok((parse_str_jst_date(date_str.to_string())?, false));
+}
+
+// 年月の文字列（%Y-%m）からその月の日付文字列を全て返す
+pub fn get_month_str_dates(month_str: String) -> Result<Vec<String>, Box<dyn Error>> {
+    let first_date = NaiveDate::parse_from_str(&format!("{}-01", month_str), "%Y-%m-%d")?;
+    let dates = first_date
+        .iter_days()
+        .take_while(|d| d.month() == first_date.month())
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect();
+    return Ok(dates);
+}
@@ -0,0 +1,73 @@
+// タグとタイトルに含まれるキーワードの対応
+const EVENT_TAG_KEYWORDS: [(&str, &[&str]); 9] = [
+    (
+        "boardgame",
+        &[
+            "ボードゲーム",
+            "ボドゲ",
+            "人狼",
+            "マーダーミステリー",
+            "謎解き",
+        ],
+    ),
+    (
+        "study",
+        &[
+            "勉強会",
+            "もくもく",
+            "セミナー",
+            "読書会",
+            "講座",
+            "ワークショップ",
+        ],
+    ),
+    (
+        "language",
+        &["英会話", "英語", "中国語", "韓国語", "語学", "国際交流"],
+    ),
+    (
+        "sports",
+        &[
+            "フットサル",
+            "バドミントン",
+            "テニス",
+            "ランニング",
+            "ヨガ",
+            "ボウリング",
+            "バレー",
+        ],
+    ),
+    (
+        "outdoor",
+        &[
+            "登山",
+            "ハイキング",
+            "キャンプ",
+            "BBQ",
+            "バーベキュー",
+            "散歩",
+        ],
+    ),
+    (
+        "food",
+        &["飲み会", "ランチ", "カフェ", "ディナー", "食事会", "グルメ"],
+    ),
+    ("party", &["交流会", "パーティー", "街コン", "婚活", "恋活"]),
+    (
+        "culture",
+        &["音楽", "カラオケ", "ライブ", "映画", "美術館", "写真"],
+    ),
+    (
+        "career",
+        &["起業", "副業", "ビジネス", "異業種", "キャリア"],
+    ),
+];
+
+// タイトルからタグを判定
+pub fn get_event_tags(title: &str) -> Vec<String> {
+    return EVENT_TAG_KEYWORDS
+        .iter()
+        .filter(|(_, keywords)| keywords.iter().any(|k| title.contains(k)))
+        .map(|(tag, _)| tag.to_string())
+        .collect();
+}
@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+// 日本語タイトル向けに文字bigramで比較
+const NGRAM_SIZE: usize = 2;
+
+// 記号・空白を除き、全角英数を半角小文字に揃える
+fn normalize_title(title: &str) -> Vec<char> {
+    return title
+        .chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+}
+
+pub fn get_title_ngrams(title: &str) -> HashSet<String> {
+    let chars = normalize_title(title);
+    if chars.is_empty() {
+        return HashSet::new();
+    }
+    if chars.len() < NGRAM_SIZE {
+        return HashSet::from([chars.iter().collect()]);
+    }
+    return chars
+        .windows(NGRAM_SIZE)
+        .map(|w| w.iter().collect())
+        .collect();
+}
+
+// Dice係数（0〜1）
+pub fn calc_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let common = a.intersection(b).count();
+    return (2 * common) as f64 / (a.len() + b.len()) as f64;
+}
@@ -0,0 +1,86 @@
+use reqwest::Url;
+use std::error::Error;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use tokio::net::lookup_host;
+
+fn is_public_ipv4(ip: &Ipv4Addr) -> bool {
+    let octets = ip.octets();
+    // 0.0.0.0/8（このネットワーク）
+    let is_this_network = octets[0] == 0;
+    // 100.64.0.0/10（CGNAT）
+    let is_shared = octets[0] == 100 && (octets[1] & 0xC0) == 64;
+    // 198.18.0.0/15（ベンチマーク用）
+    let is_benchmarking = octets[0] == 198 && (octets[1] & 0xFE) == 18;
+    // 240.0.0.0/4（予約済み、ブロードキャストを含む）
+    let is_reserved = octets[0] >= 240;
+    return !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || is_this_network
+        || is_shared
+        || is_benchmarking
+        || is_reserved);
+}
+
+// IPv6アドレスに埋め込まれたIPv4アドレス（IPv4射影・互換、NAT64、6to4）
+fn get_embedded_ipv4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    let segments = ip.segments();
+    let octets = ip.octets();
+    if let Some(v4) = ip.to_ipv4() {
+        return Some(v4);
+    }
+    // 64:ff9b::/96
+    if segments[..6] == [0x64, 0xFF9B, 0, 0, 0, 0] {
+        return Some(Ipv4Addr::new(
+            octets[12], octets[13], octets[14], octets[15],
+        ));
+    }
+    // 2002::/16
+    if segments[0] == 0x2002 {
+        return Some(Ipv4Addr::new(octets[2], octets[3], octets[4], octets[5]));
+    }
+    return None;
+}
+
+fn is_public_ipv6(ip: &Ipv6Addr) -> bool {
+    // ::と::1はIPv4互換アドレスとしても扱われるため先に判定
+    if ip.is_loopback() || ip.is_unspecified() {
+        return false;
+    }
+    if let Some(v4) = get_embedded_ipv4(ip) {
+        return is_public_ipv4(&v4);
+    }
+    let segments = ip.segments();
+    // fc00::/7（ユニークローカル）、fe80::/10（リンクローカル）、64:ff9b:1::/48（ローカル用NAT64）
+    let is_unique_local = (segments[0] & 0xFE00) == 0xFC00;
+    let is_link_local = (segments[0] & 0xFFC0) == 0xFE80;
+    let is_local_nat64 = segments[0] == 0x64 && segments[1] == 0xFF9B && segments[2] == 1;
+    return !(ip.is_multicast() || is_unique_local || is_link_local || is_local_nat64);
+}
+
+pub fn is_public_ip(ip: &IpAddr) -> bool {
+    return match ip {
+        IpAddr::V4(v4) => is_public_ipv4(v4),
+        IpAddr::V6(v6) => is_public_ipv6(v6),
+    };
+}
+
+// http(s)のURLのホストを名前解決し、公開アドレスのみの場合にホスト名とアドレスを返す
+pub async fn resolve_public_url(url: &str) -> Result<(Url, String, SocketAddr), Box<dyn Error>> {
+    let parsed = Url::parse(url)?;
+    if parsed.scheme() != "https" && parsed.scheme() != "http" {
+        return Err("Invalid url".into());
+    }
+    let host = match parsed.host_str() {
+        Some(h) => h.trim_start_matches('[').trim_end_matches(']').to_string(),
+        None => return Err("Invalid url".into()),
+    };
+    let port = parsed.port_or_known_default().unwrap_or(80);
+    let addrs: Vec<SocketAddr> = lookup_host((host.as_str(), port)).await?.collect();
+    if addrs.is_empty() || addrs.iter().any(|a| !is_public_ip(&a.ip())) {
+        return Err("Url host is not allowed".into());
+    }
+    return Ok((parsed, host, addrs[0]));
+}
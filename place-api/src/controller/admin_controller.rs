@@ -0,0 +1,268 @@
+use crate::model::api::admin_request::{
+    DeleteRequest, LocationRequest, SortOrderRequest, StoreRequest, StoreTypeRequest,
+};
+use crate::service::search_condition_cache_service::SearchConditionCache;
+use crate::service::{admin_service, store_import_service};
+use crate::util::admin_util;
+use actix_web::web::{Data, Json, Query};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+use mongodb::Database;
+use serde::Deserialize;
+
+#[get("/admin/get_store_list")]
+pub async fn get_store_list(db: Data<Database>, req: HttpRequest) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::get_store_list(&db).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/add_location")]
+pub async fn add_location(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<LocationRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::add_location(&db, body.into_inner()).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/update_location")]
+pub async fn update_location(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<LocationRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::update_location(&db, body.into_inner()).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/sort_locations")]
+pub async fn sort_locations(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<SortOrderRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::sort_locations(&db, body.into_inner()).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/delete_location")]
+pub async fn delete_location(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<DeleteRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::delete_location(&db, body.into_inner().id).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/add_store_type")]
+pub async fn add_store_type(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<StoreTypeRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::add_store_type(&db, body.into_inner()).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/update_store_type")]
+pub async fn update_store_type(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<StoreTypeRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::update_store_type(&db, body.into_inner()).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/sort_store_types")]
+pub async fn sort_store_types(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<SortOrderRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::sort_store_types(&db, body.into_inner()).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/delete_store_type")]
+pub async fn delete_store_type(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    req: HttpRequest,
+    body: Json<DeleteRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::delete_store_type(&db, body.into_inner().id).await;
+    if response.is_ok() {
+        cache.invalidate();
+    }
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/add_store")]
+pub async fn add_store(
+    db: Data<Database>,
+    req: HttpRequest,
+    body: Json<StoreRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::add_store(&db, body.into_inner(), None).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/update_store")]
+pub async fn update_store(
+    db: Data<Database>,
+    req: HttpRequest,
+    body: Json<StoreRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::update_store(&db, body.into_inner(), None).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/delete_store")]
+pub async fn delete_store(
+    db: Data<Database>,
+    req: HttpRequest,
+    body: Json<DeleteRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = admin_service::delete_store(&db, body.into_inner().id).await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[derive(Deserialize)]
+pub struct ImportStoresQuery {
+    format: String,
+}
+
+// 取り込みサイズの上限をルート単位で設定するためmain.rsで登録
+pub async fn import_stores(
+    db: Data<Database>,
+    req: HttpRequest,
+    query: Query<ImportStoresQuery>,
+    body: String,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = store_import_service::import_stores(&db, &query.format, &body).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
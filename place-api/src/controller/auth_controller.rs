@@ -0,0 +1,19 @@
+use crate::model::api::account_user_request::GoogleAuthCodeRequest;
+use crate::service::auth::account_user_service;
+use actix_web::web::{Data, Json};
+use actix_web::{error::ErrorUnauthorized, post, HttpResponse, Responder};
+use mongodb::Database;
+
+#[post("/login_by_google")]
+pub async fn login_by_google(
+    db: Data<Database>,
+    body: Json<GoogleAuthCodeRequest>,
+) -> impl Responder {
+    let response =
+        account_user_service::login_by_google_auth_code(&db, body.into_inner().auth_code).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorUnauthorized(e.to_string()).into(),
+    };
+}
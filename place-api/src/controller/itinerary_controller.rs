@@ -0,0 +1,23 @@
+use crate::model::api::itinerary_request::ItineraryRequest;
+use crate::model::api::lang_request::LangQuery;
+use crate::service::itinerary_service;
+use crate::util::lang_util;
+use actix_web::web::{Data, Json, Query};
+use actix_web::{error::ErrorBadRequest, post, HttpRequest, HttpResponse, Responder};
+use mongodb::Database;
+
+#[post("/plan_itinerary")]
+pub async fn plan_itinerary(
+    db: Data<Database>,
+    body: Json<ItineraryRequest>,
+    lang: Query<LangQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let lang = lang_util::get_request_lang(&req, lang.lang.as_deref());
+    let response = itinerary_service::plan_itinerary(&db, body.into_inner(), &lang).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
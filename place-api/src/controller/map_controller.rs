@@ -0,0 +1,50 @@
+use crate::model::api::lang_request::LangQuery;
+use crate::model::api::store_map_request::{StoreClusterQuery, StoreExportQuery};
+use crate::service::store_map_service;
+use crate::util::lang_util;
+use actix_web::http::header;
+use actix_web::web::{Data, Query};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError},
+    get, HttpRequest, HttpResponse, Responder,
+};
+use mongodb::Database;
+
+const GEOJSON_CONTENT_TYPE: &str = "application/geo+json";
+
+#[get("/get_store_cluster")]
+pub async fn get_store_cluster(
+    db: Data<Database>,
+    query: Query<StoreClusterQuery>,
+    lang: Query<LangQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let lang = lang_util::get_request_lang(&req, lang.lang.as_deref());
+    let response = store_map_service::get_store_clusters(&db, query.into_inner(), &lang).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok()
+            .content_type(GEOJSON_CONTENT_TYPE)
+            .json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[get("/export_stores")]
+pub async fn export_stores(db: Data<Database>, query: Query<StoreExportQuery>) -> impl Responder {
+    let response = store_map_service::export_stores(&db, query.into_inner()).await;
+
+    return match response {
+        Ok(r) => match serde_json::to_string(&r) {
+            Ok(body) => HttpResponse::Ok()
+                .content_type(GEOJSON_CONTENT_TYPE)
+                .insert_header((
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"stores.geojson\"",
+                ))
+                .body(body),
+            Err(e) => ErrorInternalServerError(e.to_string()).into(),
+        },
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -1,12 +1,69 @@
-use crate::service::search_service::get_search_condition_response;
-use actix_web::{error::ErrorInternalServerError, get, HttpResponse, Responder};
+use crate::model::api::lang_request::LangQuery;
+use crate::model::api::store_search_request::StoreSearchQuery;
+use crate::service::search_condition_cache_service::{self, SearchConditionCache};
+use crate::service::search_service::search_stores as search;
+use crate::util::lang_util;
+use actix_web::http::header;
+use actix_web::web::{Data, Query};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError},
+    get, HttpRequest, HttpResponse, Responder,
+};
+use mongodb::Database;
+
+// ブラウザでのキャッシュ期間（秒）
+const SEARCH_CONDITION_MAX_AGE: u32 = 300;
 
 #[get("/get_search_condition")]
-pub async fn get_search_condition() -> impl Responder {
-    let response = get_search_condition_response();
+pub async fn get_search_condition(
+    db: Data<Database>,
+    cache: Data<SearchConditionCache>,
+    lang: Query<LangQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let lang = lang_util::get_request_lang(&req, lang.lang.as_deref());
+    let response = search_condition_cache_service::get_search_condition(&db, &cache, &lang).await;
 
     return match response {
-        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Ok(r) => {
+            let cache_control = format!("public, max-age={}", SEARCH_CONDITION_MAX_AGE);
+            // ETagが一致すれば本文は返さない
+            let not_modified = req
+                .headers()
+                .get(header::IF_NONE_MATCH)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.split(',').any(|t| t.trim() == r.etag || t.trim() == "*"))
+                .unwrap_or(false);
+            if not_modified {
+                return HttpResponse::NotModified()
+                    .insert_header((header::ETAG, r.etag))
+                    .insert_header((header::CACHE_CONTROL, cache_control))
+                    .insert_header((header::VARY, "Accept-Language"))
+                    .finish();
+            }
+            HttpResponse::Ok()
+                .content_type("application/json")
+                .insert_header((header::ETAG, r.etag))
+                .insert_header((header::CACHE_CONTROL, cache_control))
+                .insert_header((header::VARY, "Accept-Language"))
+                .body(r.body)
+        }
         Err(e) => ErrorInternalServerError(e.to_string()).into(),
     };
 }
+
+#[get("/search_stores")]
+pub async fn search_stores(
+    db: Data<Database>,
+    query: Query<StoreSearchQuery>,
+    lang: Query<LangQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let lang = lang_util::get_request_lang(&req, lang.lang.as_deref());
+    let response = search(&db, query.into_inner(), &lang).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -0,0 +1,18 @@
+use crate::model::api::station_request::StationSearchQuery;
+use crate::service::station_service;
+use actix_web::web::{Data, Query};
+use actix_web::{error::ErrorBadRequest, get, HttpResponse, Responder};
+use mongodb::Database;
+
+#[get("/search_stations")]
+pub async fn search_stations(
+    db: Data<Database>,
+    query: Query<StationSearchQuery>,
+) -> impl Responder {
+    let response = station_service::search_stations(&db, query.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
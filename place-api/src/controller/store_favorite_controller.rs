@@ -0,0 +1,142 @@
+use crate::model::api::lang_request::LangQuery;
+use crate::model::api::store_favorite_request::{
+    FavoriteStoreNearQuery, StoreFavoriteRequest, StoreVisitDeleteRequest, StoreVisitListQuery,
+    StoreVisitRequest,
+};
+use crate::service::auth::auth_middleware::AuthAccount;
+use crate::service::store_favorite_service;
+use crate::util::lang_util;
+use actix_web::web::{Data, Json, Query, ReqData};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+use mongodb::Database;
+
+// /user配下（auth_middleware::require_authで認証済み）
+
+#[get("/get_favorite_store_list")]
+pub async fn get_favorite_store_list(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    lang: Query<LangQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let lang = lang_util::get_request_lang(&req, lang.lang.as_deref());
+    let response =
+        store_favorite_service::get_favorite_list(&db, auth.into_inner().account_id, &lang).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[get("/search_favorite_stores")]
+pub async fn search_favorite_stores(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    query: Query<FavoriteStoreNearQuery>,
+    lang: Query<LangQuery>,
+    req: HttpRequest,
+) -> impl Responder {
+    let lang = lang_util::get_request_lang(&req, lang.lang.as_deref());
+    let response = store_favorite_service::search_favorite_stores(
+        &db,
+        auth.into_inner().account_id,
+        query.into_inner(),
+        &lang,
+    )
+    .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/add_favorite_store")]
+pub async fn add_favorite_store(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreFavoriteRequest>,
+) -> impl Responder {
+    let response =
+        store_favorite_service::add_favorite(&db, auth.into_inner().account_id, body.into_inner())
+            .await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/delete_favorite_store")]
+pub async fn delete_favorite_store(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreFavoriteRequest>,
+) -> impl Responder {
+    let response = store_favorite_service::delete_favorite(
+        &db,
+        auth.into_inner().account_id,
+        body.into_inner(),
+    )
+    .await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[get("/get_store_visit_list")]
+pub async fn get_store_visit_list(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    query: Query<StoreVisitListQuery>,
+) -> impl Responder {
+    let response = store_favorite_service::get_visit_list(
+        &db,
+        auth.into_inner().account_id,
+        query.into_inner(),
+    )
+    .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/add_store_visit")]
+pub async fn add_store_visit(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreVisitRequest>,
+) -> impl Responder {
+    let response =
+        store_favorite_service::add_visit(&db, auth.into_inner().account_id, body.into_inner())
+            .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/delete_store_visit")]
+pub async fn delete_store_visit(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreVisitDeleteRequest>,
+) -> impl Responder {
+    let response =
+        store_favorite_service::delete_visit(&db, auth.into_inner().account_id, body.into_inner())
+            .await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -0,0 +1,69 @@
+use crate::model::api::store_review_request::{
+    StoreReviewDeleteRequest, StoreReviewListQuery, StoreReviewRequest,
+};
+use crate::service::auth::auth_middleware::AuthAccount;
+use crate::service::store_review_service;
+use actix_web::web::{Data, Json, Query, ReqData};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError},
+    get, post, HttpResponse, Responder,
+};
+use mongodb::Database;
+
+#[get("/get_store_review_list")]
+pub async fn get_store_review_list(
+    db: Data<Database>,
+    query: Query<StoreReviewListQuery>,
+) -> impl Responder {
+    let response = store_review_service::get_review_list(&db, query.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+// 以下は/user配下（auth_middleware::require_authで認証済み）
+
+#[get("/get_my_review_list")]
+pub async fn get_my_review_list(db: Data<Database>, auth: ReqData<AuthAccount>) -> impl Responder {
+    let response =
+        store_review_service::get_my_review_list(&db, auth.into_inner().account_id).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/save_store_review")]
+pub async fn save_store_review(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreReviewRequest>,
+) -> impl Responder {
+    let response =
+        store_review_service::save_review(&db, auth.into_inner().account_id, body.into_inner())
+            .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/delete_store_review")]
+pub async fn delete_store_review(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreReviewDeleteRequest>,
+) -> impl Responder {
+    let response =
+        store_review_service::delete_review(&db, auth.into_inner().account_id, body.into_inner())
+            .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -0,0 +1,99 @@
+use crate::model::api::store_submission_request::{
+    StoreSubmissionApproveRequest, StoreSubmissionListQuery, StoreSubmissionRejectRequest,
+    StoreSubmissionRequest,
+};
+use crate::service::auth::auth_middleware::AuthAccount;
+use crate::service::store_submission_service;
+use crate::util::admin_util;
+use actix_web::web::{Data, Json, Query, ReqData};
+use actix_web::{
+    error::{ErrorBadRequest, ErrorInternalServerError, ErrorUnauthorized},
+    get, post, HttpRequest, HttpResponse, Responder,
+};
+use mongodb::Database;
+
+// /user配下（auth_middleware::require_authで認証済み）
+
+#[post("/add_store_submission")]
+pub async fn add_store_submission(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+    body: Json<StoreSubmissionRequest>,
+) -> impl Responder {
+    let response = store_submission_service::add_submission(
+        &db,
+        auth.into_inner().account_id,
+        body.into_inner(),
+    )
+    .await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[get("/get_my_store_submission_list")]
+pub async fn get_my_store_submission_list(
+    db: Data<Database>,
+    auth: ReqData<AuthAccount>,
+) -> impl Responder {
+    let response =
+        store_submission_service::get_my_submission_list(&db, auth.into_inner().account_id).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[get("/admin/get_store_submission_list")]
+pub async fn get_store_submission_list(
+    db: Data<Database>,
+    req: HttpRequest,
+    query: Query<StoreSubmissionListQuery>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = store_submission_service::get_submission_list(&db, query.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorInternalServerError(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/approve_store_submission")]
+pub async fn approve_store_submission(
+    db: Data<Database>,
+    req: HttpRequest,
+    body: Json<StoreSubmissionApproveRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = store_submission_service::approve_submission(&db, body.into_inner()).await;
+
+    return match response {
+        Ok(r) => HttpResponse::Ok().content_type("application/json").json(r),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
+
+#[post("/admin/reject_store_submission")]
+pub async fn reject_store_submission(
+    db: Data<Database>,
+    req: HttpRequest,
+    body: Json<StoreSubmissionRejectRequest>,
+) -> impl Responder {
+    if !admin_util::is_admin_request(&req) {
+        return ErrorUnauthorized("Unauthorized").into();
+    }
+    let response = store_submission_service::reject_submission(&db, body.into_inner()).await;
+
+    return match response {
+        Ok(_r) => HttpResponse::Ok().json(""),
+        Err(e) => ErrorBadRequest(e.to_string()).into(),
+    };
+}
@@ -1,30 +1,137 @@
 use actix_cors::Cors;
 use actix_web::http;
+use actix_web::middleware::from_fn;
+use actix_web::web::{self, Data, PayloadConfig};
 use actix_web::App;
 use actix_web::HttpServer;
 use dotenv;
+use mongodb::Database;
 use std::env;
 
 mod controller {
+    pub mod admin_controller;
+    pub mod auth_controller;
+    pub mod itinerary_controller;
+    pub mod map_controller;
     pub mod search_controller;
+    pub mod station_controller;
+    pub mod store_favorite_controller;
+    pub mod store_review_controller;
+    pub mod store_submission_controller;
 }
 
 mod service {
+    pub mod auth {
+        pub mod account_user_service;
+        pub mod auth_middleware;
+        pub mod google_auth_service;
+        pub mod jwt_service;
+    }
+    pub mod admin_service;
+    pub mod itinerary_service;
+    pub mod search_condition_cache_service;
     pub mod search_service;
+    pub mod station_service;
+    pub mod store_favorite_service;
+    pub mod store_import_service;
+    pub mod store_map_service;
+    pub mod store_review_service;
+    pub mod store_submission_service;
 }
 
 mod model {
     pub mod api {
+        pub mod account_user_request;
+        pub mod account_user_response;
+        pub mod admin_request;
+        pub mod geojson_response;
+        pub mod itinerary_request;
+        pub mod itinerary_response;
+        pub mod lang_request;
         pub mod search_condition_response;
+        pub mod station_import_response;
+        pub mod station_request;
+        pub mod station_response;
+        pub mod store_favorite_request;
+        pub mod store_favorite_response;
+        pub mod store_import_response;
+        pub mod store_map_request;
+        pub mod store_response;
+        pub mod store_review_request;
+        pub mod store_review_response;
+        pub mod store_search_request;
+        pub mod store_submission_request;
+        pub mod store_submission_response;
     }
     pub mod db {
+        pub mod account_user_collection;
+        pub mod opening_hours_collection;
         pub mod search_condition_collection;
+        pub mod station_collection;
+        pub mod store_collection;
+        pub mod store_favorite_collection;
+        pub mod store_review_collection;
+        pub mod store_submission_collection;
     }
 }
 
 mod repository {
+    pub mod account_user_repository;
     pub mod mongodb_client;
     pub mod search_condition_repository;
+    pub mod station_repository;
+    pub mod store_favorite_repository;
+    pub mod store_repository;
+    pub mod store_review_repository;
+    pub mod store_submission_repository;
+}
+
+mod util {
+    pub mod admin_util;
+    pub mod config_util;
+    pub mod date_util;
+    pub mod geo_util;
+    pub mod lang_util;
+    pub mod search_text_util;
+    pub mod validate_util;
+}
+
+async fn import_stations_command(db: &Database, args: &[String]) -> std::io::Result<()> {
+    let path = match args {
+        [path] => path,
+        _ => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "usage: place-api import_stations <csv file>",
+            ))
+        }
+    };
+    let data = std::fs::read_to_string(path)?;
+    let report = service::station_service::import_stations(db, &data)
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    return Ok(());
+}
+
+const IMPORT_PAYLOAD_LIMIT: usize = 10 * 1024 * 1024;
+
+async fn import_stores_command(db: &Database, args: &[String]) -> std::io::Result<()> {
+    let (format, path) = match args {
+        [format, path] => (format, path),
+        _ => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "usage: place-api import_stores <csv|geojson> <file>",
+            ))
+        }
+    };
+    let data = std::fs::read_to_string(path)?;
+    let report = service::store_import_service::import_stores(db, format, &data)
+        .await
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    return Ok(());
 }
 
 #[actix_web::main]
@@ -37,16 +144,113 @@ async fn main() -> std::io::Result<()> {
     // 環境毎にファイルを配置して読み込み
     dotenv::from_filename(".env.".to_string() + &environment).ok();
 
-    HttpServer::new(|| {
+    let config =
+        util::config_util::load_config().map_err(|e| std::io::Error::other(e.to_string()))?;
+    // MongoDBに接続できない場合は起動しない
+    let db = repository::mongodb_client::create_mongodb_database(&config)
+        .await
+        .map_err(|e| std::io::Error::other(format!("Can not connect to MongoDB: {}", e)))?;
+
+    // 「import_stores <csv|geojson> <ファイル>」の指定時は店舗の取り込みのみ実行
+    let args: Vec<String> = env::args().collect();
+    if args.get(1).map(|a| a.as_str()) == Some("import_stores") {
+        return import_stores_command(&db, &args[2..]).await;
+    }
+    // 「import_stations <ファイル>」の指定時は駅の取り込みと店舗の最寄り駅の再計算のみ実行
+    if args.get(1).map(|a| a.as_str()) == Some("import_stations") {
+        return import_stations_command(&db, &args[2..]).await;
+    }
+    // 「rebuild_search_tokens」の指定時は店舗の検索用トークンの再作成のみ実行
+    if args.get(1).map(|a| a.as_str()) == Some("rebuild_search_tokens") {
+        let count = service::admin_service::rebuild_store_search_tokens(&db)
+            .await
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        println!("rebuilt search tokens: {} stores", count);
+        return Ok(());
+    }
+
+    // 「backfill_store_locations」の指定時は地域未設定の店舗への地域の割り当てのみ実行
+    if args.get(1).map(|a| a.as_str()) == Some("backfill_store_locations") {
+        let count = service::admin_service::backfill_store_location_ids(&db)
+            .await
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        println!("backfilled store locations: {} stores", count);
+        return Ok(());
+    }
+
+    // 「rebuild_nearest_stations」の指定時は店舗の最寄り駅の再計算のみ実行
+    if args.get(1).map(|a| a.as_str()) == Some("rebuild_nearest_stations") {
+        let count = service::station_service::rebuild_store_nearest_stations(&db)
+            .await
+            .map_err(|e| std::io::Error::other(e.to_string()))?;
+        println!("rebuilt nearest stations: {} stores", count);
+        return Ok(());
+    }
+
+    let db = Data::new(db);
+    // 検索条件マスタのキャッシュ（全ワーカーで共有）
+    let search_condition_cache =
+        Data::new(service::search_condition_cache_service::SearchConditionCache::new());
+    let front_domain = config.front_domain.clone();
+    HttpServer::new(move || {
         let cors = Cors::default()
-            .allowed_origin(&env::var("FRONT_DOMAIN").unwrap())
+            .allowed_origin(&front_domain)
             .allowed_methods(vec!["GET", "POST", "PUT", "OPTIONS", "DELETE"])
-            .allowed_header(http::header::CONTENT_TYPE);
+            .allowed_header(http::header::CONTENT_TYPE)
+            .allowed_header(http::header::AUTHORIZATION)
+            .allowed_header(util::admin_util::ADMIN_KEY_HEADER);
         App::new()
             .wrap(cors)
+            .app_data(db.clone())
+            .app_data(search_condition_cache.clone())
             .service(controller::search_controller::get_search_condition)
+            .service(controller::search_controller::search_stores)
+            .service(controller::station_controller::search_stations)
+            .service(controller::itinerary_controller::plan_itinerary)
+            .service(controller::map_controller::get_store_cluster)
+            .service(controller::map_controller::export_stores)
+            .service(controller::store_review_controller::get_store_review_list)
+            .service(controller::auth_controller::login_by_google)
+            // ログインユーザ用
+            .service(
+                web::scope("/user")
+                    .wrap(from_fn(service::auth::auth_middleware::require_auth))
+                    .service(controller::store_favorite_controller::get_favorite_store_list)
+                    .service(controller::store_favorite_controller::search_favorite_stores)
+                    .service(controller::store_favorite_controller::add_favorite_store)
+                    .service(controller::store_favorite_controller::delete_favorite_store)
+                    .service(controller::store_favorite_controller::get_store_visit_list)
+                    .service(controller::store_favorite_controller::add_store_visit)
+                    .service(controller::store_favorite_controller::delete_store_visit)
+                    .service(controller::store_review_controller::get_my_review_list)
+                    .service(controller::store_review_controller::save_store_review)
+                    .service(controller::store_review_controller::delete_store_review)
+                    .service(controller::store_submission_controller::add_store_submission)
+                    .service(controller::store_submission_controller::get_my_store_submission_list),
+            )
+            .service(controller::admin_controller::get_store_list)
+            .service(controller::admin_controller::add_location)
+            .service(controller::admin_controller::update_location)
+            .service(controller::admin_controller::sort_locations)
+            .service(controller::admin_controller::delete_location)
+            .service(controller::admin_controller::add_store_type)
+            .service(controller::admin_controller::update_store_type)
+            .service(controller::admin_controller::sort_store_types)
+            .service(controller::admin_controller::delete_store_type)
+            .service(controller::admin_controller::add_store)
+            .service(controller::admin_controller::update_store)
+            .service(controller::admin_controller::delete_store)
+            // 店舗の一括取り込みのみ上限を広げる
+            .service(
+                web::resource("/admin/import_stores")
+                    .app_data(PayloadConfig::new(IMPORT_PAYLOAD_LIMIT))
+                    .route(web::post().to(controller::admin_controller::import_stores)),
+            )
+            .service(controller::store_submission_controller::get_store_submission_list)
+            .service(controller::store_submission_controller::approve_store_submission)
+            .service(controller::store_submission_controller::reject_store_submission)
     })
-    .bind("0.0.0.0:8080")?
+    .bind((config.bind_address.as_str(), config.port))?
     .run()
     .await
 }
@@ -0,0 +1,7 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct AccountUserResponse {
+    pub auth_token: String,
+    pub gmail: String,
+}
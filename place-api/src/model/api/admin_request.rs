@@ -0,0 +1,50 @@
+use crate::model::db::opening_hours_collection::OpeningHoursCollection;
+use crate::model::db::search_condition_collection::LocationLevel;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+pub struct LocationRequest {
+    pub id: String,
+    pub name: String,
+    // [経度, 緯度]
+    pub loc: Vec<f64>,
+    pub level: LocationLevel,
+    pub parent_id: Option<String>,
+    // 日本語以外の名称（言語コード → 名称）
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreTypeRequest {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreRequest {
+    pub id: String,
+    pub name: String,
+    pub address: Option<String>,
+    // [経度, 緯度]
+    pub loc: Vec<f64>,
+    pub store_type: String,
+    // 所属する地域（駅周辺など）
+    pub location_id: Option<String>,
+    pub hours: Option<String>,
+    pub opening_hours: Option<OpeningHoursCollection>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteRequest {
+    pub id: String,
+}
+
+// 並び順にしたIDの一覧
+#[derive(Debug, Deserialize)]
+pub struct SortOrderRequest {
+    pub ids: Vec<String>,
+}
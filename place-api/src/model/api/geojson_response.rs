@@ -0,0 +1,50 @@
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize)]
+pub struct GeoJsonFeatureCollection {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub features: Vec<GeoJsonFeature>,
+}
+
+impl GeoJsonFeatureCollection {
+    pub fn new(features: Vec<GeoJsonFeature>) -> GeoJsonFeatureCollection {
+        return GeoJsonFeatureCollection {
+            type_name: "FeatureCollection".to_string(),
+            features,
+        };
+    }
+}
+
+#[derive(Serialize)]
+pub struct GeoJsonFeature {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub geometry: GeoJsonPoint,
+    pub properties: Value,
+}
+
+impl GeoJsonFeature {
+    pub fn point(id: Option<String>, lng: f64, lat: f64, properties: Value) -> GeoJsonFeature {
+        return GeoJsonFeature {
+            type_name: "Feature".to_string(),
+            id,
+            geometry: GeoJsonPoint {
+                type_name: "Point".to_string(),
+                coordinates: vec![lng, lat],
+            },
+            properties,
+        };
+    }
+}
+
+#[derive(Serialize)]
+pub struct GeoJsonPoint {
+    #[serde(rename = "type")]
+    pub type_name: String,
+    // [経度, 緯度]
+    pub coordinates: Vec<f64>,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct ItineraryRequest {
+    // 出発地点
+    pub lat: f64,
+    pub lng: f64,
+    // 立ち寄る順の店舗種別
+    pub store_types: Vec<String>,
+    // 出発日時（未指定の場合は現在日時）
+    pub start_time: Option<String>,
+    // 出発から最後の店舗を出るまでの時間（分）
+    pub time_budget_minutes: i64,
+    // 1店舗あたりの滞在時間（分）
+    pub stay_minutes: Option<i64>,
+}
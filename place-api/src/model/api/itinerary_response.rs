@@ -0,0 +1,22 @@
+use crate::model::api::store_response::StoreResponse;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ItineraryStopResponse {
+    // distanceは出発地点からの距離、営業時間の情報は到着日時時点
+    pub store: StoreResponse,
+    // 前の地点からの徒歩の距離（メートル）と所要時間（分）
+    pub walk_distance: f64,
+    pub walk_minutes: i64,
+    pub arrival_time: String,
+    pub departure_time: String,
+}
+
+#[derive(Serialize)]
+pub struct ItineraryResponse {
+    // 徒歩の合計距離（メートル）
+    pub total_walk_distance: f64,
+    // 出発から最後の店舗を出るまでの時間（分）
+    pub total_minutes: i64,
+    pub stops: Vec<ItineraryStopResponse>,
+}
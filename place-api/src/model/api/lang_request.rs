@@ -0,0 +1,7 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct LangQuery {
+    // 表示言語（Accept-Languageより優先）
+    pub lang: Option<String>,
+}
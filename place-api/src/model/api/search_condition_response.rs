@@ -1,9 +1,13 @@
+use crate::model::db::search_condition_collection::LocationLevel;
 use serde::Serialize;
 
 #[derive(Serialize)]
 pub struct SearchConditionResponse {
+    // 名称の言語
+    pub lang: String,
     pub store_type: Vec<SearchConditionResponseKV>,
-    pub location: Vec<SearchConditionResponseKV>,
+    // 都道府県をルートとした地域のツリー
+    pub location: Vec<SearchConditionResponseLocation>,
 }
 
 #[derive(Serialize)]
@@ -11,3 +15,11 @@ pub struct SearchConditionResponseKV {
     pub key: String,
     pub value: String,
 }
+
+#[derive(Serialize)]
+pub struct SearchConditionResponseLocation {
+    pub key: String,
+    pub value: String,
+    pub level: LocationLevel,
+    pub children: Vec<SearchConditionResponseLocation>,
+}
@@ -0,0 +1,12 @@
+use crate::model::api::store_import_response::StoreImportRowResponse;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StationImportResponse {
+    pub inserted: usize,
+    pub updated: usize,
+    pub rejected: usize,
+    // 最寄り駅を再計算した店舗数
+    pub stores: usize,
+    pub rows: Vec<StoreImportRowResponse>,
+}
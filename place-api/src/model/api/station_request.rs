@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct StationSearchQuery {
+    // 駅名（前方一致、かな・カナは区別しない）
+    pub keyword: String,
+    pub limit: Option<i64>,
+}
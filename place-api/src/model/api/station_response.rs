@@ -0,0 +1,23 @@
+use crate::model::db::station_collection::StationCollection;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StationResponse {
+    pub id: String,
+    pub name: String,
+    pub lines: Vec<String>,
+    pub lat: f64,
+    pub lng: f64,
+}
+
+impl StationResponse {
+    pub fn from_collection(station: StationCollection) -> StationResponse {
+        return StationResponse {
+            lat: station.loc.get(1).copied().unwrap_or_default(),
+            lng: station.loc.first().copied().unwrap_or_default(),
+            id: station._id,
+            name: station.name,
+            lines: station.lines,
+        };
+    }
+}
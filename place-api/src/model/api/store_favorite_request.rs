@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreFavoriteRequest {
+    pub store_id: String,
+}
+
+// 指定地点の近くのお気に入り店舗
+#[derive(Debug, Deserialize)]
+pub struct FavoriteStoreNearQuery {
+    pub lat: f64,
+    pub lng: f64,
+    // 検索半径（メートル、未指定の場合は絞り込まない）
+    pub radius: Option<f64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreVisitRequest {
+    pub store_id: String,
+    // YYYY-MM-DD
+    pub visit_date: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreVisitListQuery {
+    pub store_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreVisitDeleteRequest {
+    pub id: String,
+}
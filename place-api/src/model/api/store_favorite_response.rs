@@ -0,0 +1,59 @@
+use crate::model::db::store_collection::StoreCollection;
+use crate::model::db::store_favorite_collection::{StoreFavoriteCollection, StoreVisitCollection};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StoreFavoriteResponse {
+    pub store_id: String,
+    pub name: String,
+    pub address: Option<String>,
+    pub lat: f64,
+    pub lng: f64,
+    pub store_type: String,
+    pub store_type_name: Option<String>,
+    pub create_time: i64,
+}
+
+impl StoreFavoriteResponse {
+    pub fn from_collection(
+        favorite: StoreFavoriteCollection,
+        store: StoreCollection,
+        store_type_name: Option<String>,
+    ) -> StoreFavoriteResponse {
+        return StoreFavoriteResponse {
+            store_id: favorite.store_id,
+            name: store.name,
+            address: store.address,
+            lat: store.loc.get(1).copied().unwrap_or_default(),
+            lng: store.loc.first().copied().unwrap_or_default(),
+            store_type: store.store_type,
+            store_type_name,
+            create_time: favorite.create_time,
+        };
+    }
+}
+
+#[derive(Serialize)]
+pub struct StoreVisitResponse {
+    pub id: String,
+    pub store_id: String,
+    // 削除済みの店舗の場合はNone
+    pub store_name: Option<String>,
+    pub visit_date: String,
+    pub note: Option<String>,
+}
+
+impl StoreVisitResponse {
+    pub fn from_collection(
+        visit: StoreVisitCollection,
+        store_name: Option<String>,
+    ) -> StoreVisitResponse {
+        return StoreVisitResponse {
+            id: visit._id,
+            store_id: visit.store_id,
+            store_name,
+            visit_date: visit.visit_date,
+            note: visit.note,
+        };
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StoreImportResponse {
+    pub inserted: usize,
+    pub updated: usize,
+    pub rejected: usize,
+    pub rows: Vec<StoreImportRowResponse>,
+}
+
+#[derive(Serialize)]
+pub struct StoreImportRowResponse {
+    // データ行の番号（1始まり）
+    pub row: usize,
+    pub id: Option<String>,
+    // inserted / updated / rejected
+    pub result: String,
+    pub reason: Option<String>,
+}
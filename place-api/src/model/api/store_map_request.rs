@@ -0,0 +1,22 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct StoreClusterQuery {
+    // 表示範囲
+    pub min_lng: f64,
+    pub min_lat: f64,
+    pub max_lng: f64,
+    pub max_lat: f64,
+    // 地図のズームレベル
+    pub zoom: u8,
+    // カンマ区切りの店舗種別
+    pub store_type: Option<String>,
+    pub location_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreExportQuery {
+    pub location_key: Option<String>,
+    // カンマ区切りの店舗種別
+    pub store_type: Option<String>,
+}
@@ -0,0 +1,61 @@
+use crate::model::db::station_collection::StoreStationCollection;
+use crate::model::db::store_collection::StoreDistanceCollection;
+use crate::util::date_util;
+use chrono::DateTime;
+use chrono_tz::Tz;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StoreResponse {
+    pub id: String,
+    pub name: String,
+    pub address: Option<String>,
+    pub lat: f64,
+    pub lng: f64,
+    pub store_type: String,
+    // 表示言語での店舗種別名
+    pub store_type_name: Option<String>,
+    pub hours: Option<String>,
+    pub nearest_stations: Vec<StoreStationCollection>,
+    pub distance: f64,
+    // キーワード指定時の一致度（0〜1）
+    pub relevance: Option<f64>,
+    // レビューの評価（未レビューの場合はNone・0件）
+    pub rating_average: Option<f64>,
+    pub rating_count: i64,
+    // 営業時間が登録されている場合のみ、判定日時時点の情報
+    pub today_hours: Option<Vec<String>>,
+    pub open_now: Option<bool>,
+    pub next_open_time: Option<String>,
+}
+
+impl StoreResponse {
+    pub fn from_collection(
+        s: StoreDistanceCollection,
+        evaluate_time: DateTime<Tz>,
+        relevance: Option<f64>,
+        store_type_name: Option<String>,
+    ) -> StoreResponse {
+        let opening_hours = s.store.opening_hours.as_ref();
+        return StoreResponse {
+            id: s.store._id.clone(),
+            name: s.store.name.clone(),
+            address: s.store.address.clone(),
+            lat: s.store.loc.get(1).copied().unwrap_or_default(),
+            lng: s.store.loc.first().copied().unwrap_or_default(),
+            store_type: s.store.store_type.clone(),
+            store_type_name,
+            hours: s.store.hours.clone(),
+            nearest_stations: s.store.nearest_stations.clone(),
+            distance: s.distance.round(),
+            relevance,
+            rating_average: s.store.rating.as_ref().map(|r| r.average),
+            rating_count: s.store.rating.as_ref().map(|r| r.count).unwrap_or(0),
+            today_hours: opening_hours.map(|o| o.get_day_hours(evaluate_time.date_naive())),
+            open_now: opening_hours.map(|o| o.is_open_at(evaluate_time)),
+            next_open_time: opening_hours
+                .and_then(|o| o.get_next_open_time(evaluate_time))
+                .map(date_util::format_jst_date_time),
+        };
+    }
+}
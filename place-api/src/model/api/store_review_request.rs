@@ -0,0 +1,21 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreReviewRequest {
+    pub store_id: String,
+    // 1〜5
+    pub rating: i32,
+    pub text: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreReviewDeleteRequest {
+    pub store_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreReviewListQuery {
+    pub store_id: String,
+    pub offset: Option<u64>,
+    pub limit: Option<i64>,
+}
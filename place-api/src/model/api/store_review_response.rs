@@ -0,0 +1,30 @@
+use crate::model::db::store_review_collection::{StoreRatingCollection, StoreReviewCollection};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StoreReviewResponse {
+    pub store_id: String,
+    pub rating: i32,
+    pub text: Option<String>,
+    pub create_time: i64,
+    pub update_time: i64,
+}
+
+impl StoreReviewResponse {
+    pub fn from_collection(review: StoreReviewCollection) -> StoreReviewResponse {
+        return StoreReviewResponse {
+            store_id: review.store_id,
+            rating: review.rating,
+            text: review.text,
+            create_time: review.create_time,
+            update_time: review.update_time,
+        };
+    }
+}
+
+#[derive(Serialize)]
+pub struct StoreReviewListResponse {
+    // 未レビューの場合は件数0
+    pub rating: StoreRatingCollection,
+    pub reviews: Vec<StoreReviewResponse>,
+}
@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+// 並び順（未指定の場合はキーワードの一致度、距離の順）
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StoreSearchSort {
+    Distance,
+    // 評価と距離の両方を考慮
+    BestRated,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreSearchQuery {
+    pub location_key: Option<String>,
+    // 駅を中心に検索（location_keyより優先）
+    pub station_key: Option<String>,
+    pub lat: Option<f64>,
+    pub lng: Option<f64>,
+    // 検索半径（メートル）
+    pub radius: Option<f64>,
+    // カンマ区切りの店舗種別
+    pub store_type: Option<String>,
+    pub limit: Option<i64>,
+    // 店名・住所のキーワード
+    pub keyword: Option<String>,
+    // 現在営業中の店舗のみ
+    #[serde(default)]
+    pub open_now: bool,
+    // 指定日時に営業中の店舗のみ
+    pub open_at: Option<String>,
+    // 評価の平均の下限（1〜5）
+    pub min_rating: Option<f64>,
+    pub sort: Option<StoreSearchSort>,
+}
@@ -0,0 +1,34 @@
+use crate::model::db::opening_hours_collection::OpeningHoursCollection;
+use crate::model::db::store_submission_collection::StoreSubmissionStatus;
+use serde::Deserialize;
+
+// store_idを指定した場合は既存店舗の修正（指定した項目のみ変更）
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreSubmissionRequest {
+    pub store_id: Option<String>,
+    pub name: Option<String>,
+    pub address: Option<String>,
+    // [経度, 緯度]
+    pub loc: Option<Vec<f64>>,
+    pub store_type: Option<String>,
+    pub location_id: Option<String>,
+    pub hours: Option<String>,
+    pub opening_hours: Option<OpeningHoursCollection>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreSubmissionListQuery {
+    // 未指定の場合は未対応のもの
+    pub status: Option<StoreSubmissionStatus>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreSubmissionApproveRequest {
+    pub id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreSubmissionRejectRequest {
+    pub id: String,
+    pub reason: String,
+}
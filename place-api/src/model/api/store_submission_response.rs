@@ -0,0 +1,49 @@
+use crate::model::db::store_submission_collection::{
+    StoreSubmissionCollection, StoreSubmissionFieldsCollection, StoreSubmissionStatus,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Serialize)]
+pub struct StoreSubmissionDiffResponse {
+    pub field: String,
+    pub current: Value,
+    pub proposed: Value,
+}
+
+#[derive(Serialize)]
+pub struct StoreSubmissionResponse {
+    pub id: String,
+    pub account_id: String,
+    pub store_id: Option<String>,
+    pub fields: StoreSubmissionFieldsCollection,
+    pub diff: Vec<StoreSubmissionDiffResponse>,
+    pub status: StoreSubmissionStatus,
+    pub reject_reason: Option<String>,
+    pub create_time: i64,
+    pub review_time: Option<i64>,
+}
+
+impl StoreSubmissionResponse {
+    pub fn from_collection(submission: StoreSubmissionCollection) -> StoreSubmissionResponse {
+        return StoreSubmissionResponse {
+            id: submission._id,
+            account_id: submission.account_id,
+            store_id: submission.store_id,
+            fields: submission.fields,
+            diff: submission
+                .diff
+                .into_iter()
+                .map(|d| StoreSubmissionDiffResponse {
+                    field: d.field,
+                    current: d.current.into_relaxed_extjson(),
+                    proposed: d.proposed.into_relaxed_extjson(),
+                })
+                .collect(),
+            status: submission.status,
+            reject_reason: submission.reject_reason,
+            create_time: submission.create_time,
+            review_time: submission.review_time,
+        };
+    }
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AccountUserCollection {
+    pub _id: String,
+    pub gmail: String,
+    pub create_time: i64,
+}
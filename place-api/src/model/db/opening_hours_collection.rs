@@ -0,0 +1,230 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone};
+use chrono_tz::{Asia::Tokyo, Tz};
+use serde::{Deserialize, Serialize};
+
+// 営業時間の1区間（closeがopen以前の場合は翌日まで営業）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OpeningTimeRange {
+    // HH:MM
+    pub open: String,
+    pub close: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OpeningHoursDay {
+    // 0:日曜〜6:土曜
+    pub weekday: u32,
+    pub ranges: Vec<OpeningTimeRange>,
+}
+
+// 週ごとの営業時間（日本時間で判定）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OpeningHoursCollection {
+    pub weekly: Vec<OpeningHoursDay>,
+    // 臨時休業日（YYYY-MM-DD）
+    #[serde(default)]
+    pub closed_dates: Vec<String>,
+}
+
+// 次の営業開始を探す日数
+const NEXT_OPEN_SEARCH_DAYS: i64 = 14;
+
+fn parse_time(time_str: &str) -> Option<NaiveTime> {
+    return NaiveTime::parse_from_str(time_str, "%H:%M").ok();
+}
+
+impl OpeningTimeRange {
+    fn times(&self) -> Option<(NaiveTime, NaiveTime)> {
+        return Some((parse_time(&self.open)?, parse_time(&self.close)?));
+    }
+}
+
+impl OpeningHoursCollection {
+    pub fn validate(&self) -> Result<(), String> {
+        for day in &self.weekly {
+            if day.weekday > 6 {
+                return Err("weekday must be 0-6".to_string());
+            }
+            if day.ranges.iter().any(|r| r.times().is_none()) {
+                return Err("open/close must be HH:MM".to_string());
+            }
+        }
+        if self
+            .closed_dates
+            .iter()
+            .any(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").is_err())
+        {
+            return Err("closed_dates must be YYYY-MM-DD".to_string());
+        }
+        return Ok(());
+    }
+
+    // 指定日の営業区間（休業日は空）
+    fn get_ranges(&self, date: NaiveDate) -> Vec<(NaiveTime, NaiveTime)> {
+        if self
+            .closed_dates
+            .contains(&date.format("%Y-%m-%d").to_string())
+        {
+            return Vec::new();
+        }
+        let weekday = date.weekday().num_days_from_sunday();
+        return self
+            .weekly
+            .iter()
+            .filter(|d| d.weekday == weekday)
+            .flat_map(|d| d.ranges.iter().filter_map(|r| r.times()))
+            .collect();
+    }
+
+    pub fn is_open_at(&self, date_time: DateTime<Tz>) -> bool {
+        let date = date_time.date_naive();
+        let time = date_time.time();
+        // 当日の区間
+        let open_today = self.get_ranges(date).into_iter().any(|(open, close)| {
+            if open < close {
+                open <= time && time < close
+            } else {
+                open <= time
+            }
+        });
+        // 前日から日付をまたぐ区間
+        let open_from_yesterday = match date.pred_opt() {
+            Some(yesterday) => self
+                .get_ranges(yesterday)
+                .into_iter()
+                .any(|(open, close)| close <= open && time < close),
+            None => false,
+        };
+        return open_today || open_from_yesterday;
+    }
+
+    // 1つの営業区間で開始から終了まで営業しているか（区間の切れ目をまたぐ場合は対象外）
+    pub fn is_open_during(&self, from: DateTime<Tz>, to: DateTime<Tz>) -> bool {
+        if to <= from {
+            return self.is_open_at(from);
+        }
+        let (from, to) = (from.naive_local(), to.naive_local());
+        let base_date = from.date();
+        // 前日から日付をまたぐ区間と当日の区間
+        return [base_date.pred_opt(), Some(base_date)]
+            .into_iter()
+            .flatten()
+            .any(|date| {
+                self.get_ranges(date).into_iter().any(|(open, close)| {
+                    let start = date.and_time(open);
+                    let end = if open < close {
+                        date.and_time(close)
+                    } else {
+                        date.and_time(close) + Duration::days(1)
+                    };
+                    start <= from && to <= end
+                })
+            });
+    }
+
+    // 指定日の営業時間（HH:MM-HH:MM）
+    pub fn get_day_hours(&self, date: NaiveDate) -> Vec<String> {
+        let mut ranges = self.get_ranges(date);
+        ranges.sort();
+        return ranges
+            .into_iter()
+            .map(|(open, close)| format!("{}-{}", open.format("%H:%M"), close.format("%H:%M")))
+            .collect();
+    }
+
+    // 指定日時より後の営業開始日時
+    pub fn get_next_open_time(&self, date_time: DateTime<Tz>) -> Option<DateTime<Tz>> {
+        let base_date = date_time.date_naive();
+        for i in 0..NEXT_OPEN_SEARCH_DAYS {
+            let date = base_date + Duration::days(i);
+            let mut opens: Vec<NaiveTime> = self
+                .get_ranges(date)
+                .into_iter()
+                .map(|(open, _)| open)
+                .collect();
+            opens.sort();
+            let next = opens
+                .into_iter()
+                .filter_map(|open| Tokyo.from_local_datetime(&date.and_time(open)).single())
+                .find(|d| *d > date_time);
+            if next.is_some() {
+                return next;
+            }
+        }
+        return None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-01は月曜日
+    fn jst(day: u32, hour: u32, min: u32) -> DateTime<Tz> {
+        return Tokyo.with_ymd_and_hms(2024, 1, day, hour, min, 0).unwrap();
+    }
+
+    fn opening_hours(
+        weekdays: &[u32],
+        open: &str,
+        close: &str,
+        closed_dates: &[&str],
+    ) -> OpeningHoursCollection {
+        return OpeningHoursCollection {
+            weekly: weekdays
+                .iter()
+                .map(|weekday| OpeningHoursDay {
+                    weekday: *weekday,
+                    ranges: vec![OpeningTimeRange {
+                        open: open.to_string(),
+                        close: close.to_string(),
+                    }],
+                })
+                .collect(),
+            closed_dates: closed_dates.iter().map(|d| d.to_string()).collect(),
+        };
+    }
+
+    #[test]
+    fn overnight_range_is_open_after_midnight() {
+        let hours = opening_hours(&[1], "22:00", "02:00", &[]);
+        assert!(!hours.is_open_at(jst(1, 21, 59)));
+        assert!(hours.is_open_at(jst(1, 23, 0)));
+        assert!(hours.is_open_at(jst(2, 1, 0)));
+        assert!(!hours.is_open_at(jst(2, 2, 0)));
+        assert!(hours.is_open_during(jst(1, 23, 0), jst(2, 1, 30)));
+        assert!(!hours.is_open_during(jst(1, 23, 0), jst(2, 2, 30)));
+        assert_eq!(hours.get_next_open_time(jst(2, 1, 0)), Some(jst(8, 22, 0)));
+    }
+
+    #[test]
+    fn closed_date_is_not_open() {
+        let hours = opening_hours(&[1, 2], "10:00", "18:00", &["2024-01-01"]);
+        assert!(!hours.is_open_at(jst(1, 12, 0)));
+        assert!(hours.is_open_at(jst(2, 12, 0)));
+        assert!(!hours.is_open_during(jst(1, 11, 0), jst(1, 12, 0)));
+        assert!(hours.is_open_during(jst(2, 11, 0), jst(2, 12, 0)));
+
+        // 休業日から日付をまたぐ区間も休業
+        let overnight = opening_hours(&[1], "22:00", "02:00", &["2024-01-01"]);
+        assert!(!overnight.is_open_at(jst(2, 1, 0)));
+    }
+
+    #[test]
+    fn midnight_to_midnight_is_open_all_day() {
+        let hours = opening_hours(&[1], "00:00", "00:00", &[]);
+        assert!(hours.is_open_at(jst(1, 0, 0)));
+        assert!(hours.is_open_at(jst(1, 12, 0)));
+        assert!(hours.is_open_at(jst(1, 23, 59)));
+        assert!(!hours.is_open_at(jst(2, 0, 0)));
+        assert!(hours.is_open_during(jst(1, 0, 0), jst(1, 23, 59)));
+        assert!(!hours.is_open_during(jst(1, 12, 0), jst(2, 0, 30)));
+    }
+
+    #[test]
+    fn next_open_skips_closed_date() {
+        let hours = opening_hours(&[1, 2, 3], "10:00", "18:00", &["2024-01-02"]);
+        assert_eq!(hours.get_next_open_time(jst(1, 9, 0)), Some(jst(1, 10, 0)));
+        assert_eq!(hours.get_next_open_time(jst(1, 19, 0)), Some(jst(3, 10, 0)));
+    }
+}
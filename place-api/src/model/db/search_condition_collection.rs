@@ -1,17 +1,81 @@
+use crate::util::lang_util;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize)]
+// 地域の階層（都道府県 > 市区町村 > 駅周辺）
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocationLevel {
+    Prefecture,
+    City,
+    #[default]
+    StationArea,
+}
+
+impl LocationLevel {
+    // 親に指定できる階層
+    pub fn parent_level(&self) -> Option<LocationLevel> {
+        return match self {
+            LocationLevel::Prefecture => None,
+            LocationLevel::City => Some(LocationLevel::Prefecture),
+            LocationLevel::StationArea => Some(LocationLevel::City),
+        };
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct LocationCollection {
     pub _id: String,
     pub name: String,
     pub loc: Vec<f64>,
     pub sort_order: i32,
     pub update_time: i64,
+    #[serde(default)]
+    pub level: LocationLevel,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    // 日本語以外の名称（言語コード → 名称）
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+}
+
+impl LocationCollection {
+    pub fn get_name(&self, lang: &str) -> String {
+        return lang_util::get_localized_name(&self.name, &self.names, lang);
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+// 指定した地域と配下の地域のID
+pub fn get_descendant_location_ids(locations: &[LocationCollection], id: &str) -> Vec<String> {
+    let mut results = vec![id.to_string()];
+    let mut i = 0;
+    while i < results.len() {
+        let parent_id = results[i].clone();
+        results.extend(
+            locations
+                .iter()
+                .filter(|l| l.parent_id.as_deref() == Some(parent_id.as_str()))
+                .map(|l| l._id.clone()),
+        );
+        i += 1;
+    }
+    return results;
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StoreTypeCollection {
     pub _id: String,
     pub name: String,
     pub sort_order: i32,
+    #[serde(default)]
+    pub update_time: i64,
+    // 日本語以外の名称（言語コード → 名称）
+    #[serde(default)]
+    pub names: HashMap<String, String>,
+}
+
+impl StoreTypeCollection {
+    pub fn get_name(&self, lang: &str) -> String {
+        return lang_util::get_localized_name(&self.name, &self.names, lang);
+    }
 }
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StationCollection {
+    pub _id: String,
+    pub name: String,
+    // 駅名の正規化した文字列（駅名検索用）
+    pub search_name: String,
+    // 路線名
+    pub lines: Vec<String>,
+    // [経度, 緯度]
+    pub loc: Vec<f64>,
+    pub update_time: i64,
+}
+
+// $geoNearの検索結果（distanceはメートル）
+#[derive(Debug, Deserialize)]
+pub struct StationDistanceCollection {
+    #[serde(flatten)]
+    pub station: StationCollection,
+    pub distance: f64,
+}
+
+// 店舗の最寄り駅（駅データの取り込み・店舗の更新時に計算）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreStationCollection {
+    pub station_id: String,
+    pub name: String,
+    pub lines: Vec<String>,
+    // 直線距離（メートル）
+    pub distance: f64,
+    // 徒歩の所要時間（分）
+    pub walk_minutes: i64,
+}
@@ -0,0 +1,63 @@
+use crate::model::db::opening_hours_collection::OpeningHoursCollection;
+use crate::model::db::station_collection::StoreStationCollection;
+use crate::model::db::store_review_collection::StoreRatingCollection;
+use crate::util::search_text_util;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreCollection {
+    pub _id: String,
+    pub name: String,
+    pub address: Option<String>,
+    // [経度, 緯度]
+    pub loc: Vec<f64>,
+    pub store_type: String,
+    // 所属する地域
+    #[serde(default)]
+    pub location_id: Option<String>,
+    // 営業時間（自由記述）
+    pub hours: Option<String>,
+    #[serde(default)]
+    pub opening_hours: Option<OpeningHoursCollection>,
+    pub update_time: i64,
+    // 最後に変更を提案したユーザ（管理者が直接更新した場合はNone）
+    #[serde(default)]
+    pub update_account_id: Option<String>,
+    // 店名・住所の正規化したbigram（キーワード検索用）
+    #[serde(default)]
+    pub search_tokens: Vec<String>,
+    // 近い順の最寄り駅
+    #[serde(default)]
+    pub nearest_stations: Vec<StoreStationCollection>,
+    // レビューの集計（店舗の更新では変更しない）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<StoreRatingCollection>,
+}
+
+impl StoreCollection {
+    pub fn with_search_tokens(mut self) -> StoreCollection {
+        self.search_tokens =
+            search_text_util::get_store_search_tokens(&self.name, self.address.as_deref());
+        return self;
+    }
+}
+
+// $geoNearの検索結果（distanceはメートル）
+#[derive(Clone, Debug, Deserialize)]
+pub struct StoreDistanceCollection {
+    #[serde(flatten)]
+    pub store: StoreCollection,
+    pub distance: f64,
+}
+
+// 格子ごとの店舗の集計（1件の場合は店舗そのもの）
+#[derive(Debug, Deserialize)]
+pub struct StoreClusterCollection {
+    pub count: i64,
+    // 格子内の店舗の平均座標
+    pub lng: f64,
+    pub lat: f64,
+    pub store_id: String,
+    pub name: String,
+    pub store_type: String,
+}
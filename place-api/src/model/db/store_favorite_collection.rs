@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+// ユーザのお気に入り店舗（ユーザと店舗の組で一意）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreFavoriteCollection {
+    pub _id: String,
+    pub account_id: String,
+    pub store_id: String,
+    pub create_time: i64,
+}
+
+// ユーザの店舗への訪問記録
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreVisitCollection {
+    pub _id: String,
+    pub account_id: String,
+    pub store_id: String,
+    // YYYY-MM-DD
+    pub visit_date: String,
+    pub note: Option<String>,
+    pub create_time: i64,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+// 店舗のレビュー（ユーザと店舗の組で一意）
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreReviewCollection {
+    pub _id: String,
+    pub account_id: String,
+    pub store_id: String,
+    // 1〜5
+    pub rating: i32,
+    pub text: Option<String>,
+    pub create_time: i64,
+    pub update_time: i64,
+}
+
+// 店舗ごとの評価の集計（レビューの登録・削除時に更新）
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StoreRatingCollection {
+    pub average: f64,
+    pub count: i64,
+    // 評価1〜5の件数
+    pub distribution: Vec<i64>,
+}
@@ -0,0 +1,49 @@
+use crate::model::db::opening_hours_collection::OpeningHoursCollection;
+use mongodb::bson::Bson;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StoreSubmissionStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+// 提案された店舗の項目（修正の場合は変更する項目のみ）
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StoreSubmissionFieldsCollection {
+    pub name: Option<String>,
+    pub address: Option<String>,
+    // [経度, 緯度]
+    pub loc: Option<Vec<f64>>,
+    pub store_type: Option<String>,
+    pub location_id: Option<String>,
+    pub hours: Option<String>,
+    pub opening_hours: Option<OpeningHoursCollection>,
+}
+
+// 提案時点の店舗との項目ごとの差分
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreSubmissionDiffCollection {
+    pub field: String,
+    // 新規店舗の場合はNull
+    pub current: Bson,
+    pub proposed: Bson,
+}
+
+// ユーザからの店舗の新規登録・修正の提案
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoreSubmissionCollection {
+    pub _id: String,
+    pub account_id: String,
+    // 修正の場合の対象店舗（新規の場合はNone）
+    pub store_id: Option<String>,
+    pub fields: StoreSubmissionFieldsCollection,
+    pub diff: Vec<StoreSubmissionDiffCollection>,
+    pub status: StoreSubmissionStatus,
+    pub reject_reason: Option<String>,
+    pub create_time: i64,
+    // 承認・却下した日時
+    pub review_time: Option<i64>,
+}
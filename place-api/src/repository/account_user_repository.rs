@@ -0,0 +1,22 @@
+use crate::model::db::account_user_collection::AccountUserCollection;
+use mongodb::bson::doc;
+use mongodb::Database;
+use std::error::Error;
+
+pub async fn get_account_user_by_gmail(
+    db: &Database,
+    gmail: String,
+) -> Result<Option<AccountUserCollection>, Box<dyn Error>> {
+    let col = db.collection::<AccountUserCollection>("account_user");
+    let result = col.find_one(doc! { "gmail": gmail }, None).await?;
+    return Ok(result);
+}
+
+pub async fn add_account_user(
+    db: &Database,
+    account_user: AccountUserCollection,
+) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<AccountUserCollection>("account_user");
+    col.insert_one(account_user, None).await?;
+    return Ok(());
+}
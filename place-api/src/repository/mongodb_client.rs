@@ -1,10 +1,22 @@
-use mongodb::sync::Client;
-use mongodb::sync::Database;
-use std::env;
+use crate::util::config_util::AppConfig;
+use mongodb::bson::doc;
+use mongodb::options::ClientOptions;
+use mongodb::{Client, Database};
 use std::error::Error;
+use std::time::Duration;
 
-pub fn get_mongodb_db_connection() -> Result<Database, Box<dyn Error>> {
-    let client = Client::with_uri_str(&env::var("DB_CONNECTION")?)?;
-    let database = client.database(&env::var("DB_NAME")?);
+// 接続できない場合に起動を中止するまでの時間
+const SERVER_SELECTION_TIMEOUT_SEC: u64 = 5;
+
+// 起動時に1度だけ作成し、コネクションプールを共有
+pub async fn create_mongodb_database(config: &AppConfig) -> Result<Database, Box<dyn Error>> {
+    let mut client_options = ClientOptions::parse(&config.db_connection).await?;
+    client_options.max_pool_size = Some(config.db_max_pool_size);
+    client_options.server_selection_timeout =
+        Some(Duration::from_secs(SERVER_SELECTION_TIMEOUT_SEC));
+    let client = Client::with_options(client_options)?;
+    let database = client.database(&config.db_name);
+    // 疎通確認
+    database.run_command(doc! { "ping": 1 }, None).await?;
     return Ok(database);
 }
@@ -1,25 +1,230 @@
 use crate::model::db::search_condition_collection::{LocationCollection, StoreTypeCollection};
-use crate::repository::mongodb_client;
-use mongodb::bson::doc;
-use mongodb::options::FindOptions;
+use futures::TryStreamExt;
+use mongodb::bson::{doc, Bson, Document};
+use mongodb::options::{FindOneOptions, FindOptions, UpdateOptions};
+use mongodb::Database;
+use std::collections::HashMap;
 use std::error::Error;
 
-pub fn get_location_list() -> Result<Vec<LocationCollection>, Box<dyn Error>> {
-    let col =
-        mongodb_client::get_mongodb_db_connection()?.collection::<LocationCollection>("location");
+pub async fn get_location_list(db: &Database) -> Result<Vec<LocationCollection>, Box<dyn Error>> {
+    let col = db.collection::<LocationCollection>("location");
     let find_options = FindOptions::builder()
         .sort(doc! { "sort_order": 1 })
         .build();
-    let results = col.find(None, find_options)?.flatten().collect();
+    let results = col.find(None, find_options).await?.try_collect().await?;
     return Ok(results);
 }
 
-pub fn get_store_type_list() -> Result<Vec<StoreTypeCollection>, Box<dyn Error>> {
-    let col = mongodb_client::get_mongodb_db_connection()?
-        .collection::<StoreTypeCollection>("store_type");
+pub async fn get_location(
+    db: &Database,
+    id: String,
+) -> Result<Option<LocationCollection>, Box<dyn Error>> {
+    let col = db.collection::<LocationCollection>("location");
+    let result = col.find_one(doc! { "_id": id }, None).await?;
+    return Ok(result);
+}
+
+pub async fn get_store_type_list(
+    db: &Database,
+) -> Result<Vec<StoreTypeCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreTypeCollection>("store_type");
     let find_options = FindOptions::builder()
         .sort(doc! { "sort_order": 1 })
         .build();
-    let results = col.find(None, find_options)?.flatten().collect();
+    let results = col.find(None, find_options).await?.try_collect().await?;
     return Ok(results);
 }
+
+pub async fn get_store_type(
+    db: &Database,
+    id: String,
+) -> Result<Option<StoreTypeCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreTypeCollection>("store_type");
+    let result = col.find_one(doc! { "_id": id }, None).await?;
+    return Ok(result);
+}
+
+pub async fn add_location(
+    db: &Database,
+    location: LocationCollection,
+) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<LocationCollection>("location");
+    col.insert_one(location, None).await?;
+    increment_version(db).await?;
+    return Ok(());
+}
+
+// 並び順以外を更新
+pub async fn update_location(
+    db: &Database,
+    location: LocationCollection,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<LocationCollection>("location");
+    let result = col
+        .update_one(
+            doc! { "_id": location._id },
+            doc! {
+                "$set": {
+                    "name": location.name,
+                    "loc": location.loc,
+                    "level": mongodb::bson::to_bson(&location.level)?,
+                    "parent_id": location.parent_id,
+                    "names": mongodb::bson::to_bson(&location.names)?,
+                    "update_time": location.update_time
+                }
+            },
+            None,
+        )
+        .await?;
+    increment_version(db).await?;
+    return Ok(result.matched_count);
+}
+
+pub async fn update_location_sort_order(
+    db: &Database,
+    id: String,
+    sort_order: i32,
+    update_time: i64,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<LocationCollection>("location");
+    let result = col
+        .update_one(
+            doc! { "_id": id },
+            doc! { "$set": { "sort_order": sort_order, "update_time": update_time } },
+            None,
+        )
+        .await?;
+    increment_version(db).await?;
+    return Ok(result.matched_count);
+}
+
+pub async fn delete_location(db: &Database, id: String) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<LocationCollection>("location");
+    let result = col.delete_one(doc! { "_id": id }, None).await?;
+    increment_version(db).await?;
+    return Ok(result.deleted_count);
+}
+
+pub async fn add_store_type(
+    db: &Database,
+    store_type: StoreTypeCollection,
+) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<StoreTypeCollection>("store_type");
+    col.insert_one(store_type, None).await?;
+    increment_version(db).await?;
+    return Ok(());
+}
+
+pub async fn update_store_type(
+    db: &Database,
+    id: String,
+    name: String,
+    names: HashMap<String, String>,
+    update_time: i64,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreTypeCollection>("store_type");
+    let result = col
+        .update_one(
+            doc! { "_id": id },
+            doc! {
+                "$set": {
+                    "name": name,
+                    "names": mongodb::bson::to_bson(&names)?,
+                    "update_time": update_time
+                }
+            },
+            None,
+        )
+        .await?;
+    increment_version(db).await?;
+    return Ok(result.matched_count);
+}
+
+pub async fn update_store_type_sort_order(
+    db: &Database,
+    id: String,
+    sort_order: i32,
+    update_time: i64,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreTypeCollection>("store_type");
+    let result = col
+        .update_one(
+            doc! { "_id": id },
+            doc! { "$set": { "sort_order": sort_order, "update_time": update_time } },
+            None,
+        )
+        .await?;
+    increment_version(db).await?;
+    return Ok(result.matched_count);
+}
+
+pub async fn delete_store_type(db: &Database, id: String) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreTypeCollection>("store_type");
+    let result = col.delete_one(doc! { "_id": id }, None).await?;
+    increment_version(db).await?;
+    return Ok(result.deleted_count);
+}
+
+// 地域・店舗種別の書き込みごとに更新するバージョン（複数インスタンスのキャッシュ判定用）
+async fn increment_version(db: &Database) -> Result<(), Box<dyn Error>> {
+    let options = UpdateOptions::builder().upsert(true).build();
+    db.collection::<Document>("search_condition_version")
+        .update_one(
+            doc! { "_id": "search_condition" },
+            doc! { "$inc": { "version": 1_i64 } },
+            options,
+        )
+        .await?;
+    return Ok(());
+}
+
+fn bson_to_i64(value: Option<&Bson>) -> Option<i64> {
+    return match value {
+        Some(Bson::Int32(i)) => Some(*i as i64),
+        Some(Bson::Int64(i)) => Some(*i),
+        _ => None,
+    };
+}
+
+// 地域・店舗種別の最終更新日時
+async fn get_max_update_time(db: &Database) -> Result<i64, Box<dyn Error>> {
+    let find_options = FindOneOptions::builder()
+        .sort(doc! { "update_time": -1 })
+        .projection(doc! { "update_time": 1 })
+        .build();
+    let mut max_update_time = 0;
+    for collection_name in ["location", "store_type"] {
+        let result = db
+            .collection::<Document>(collection_name)
+            .find_one(None, find_options.clone())
+            .await?;
+        if let Some(update_time) = result.and_then(|d| bson_to_i64(d.get("update_time"))) {
+            max_update_time = max_update_time.max(update_time);
+        }
+    }
+    return Ok(max_update_time);
+}
+
+// キャッシュの判定用のキー
+// 書き込みのバージョンに加え、マイグレーションでの変更も拾えるよう最終更新日時と件数を含める
+pub async fn get_search_condition_version(db: &Database) -> Result<String, Box<dyn Error>> {
+    let version = db
+        .collection::<Document>("search_condition_version")
+        .find_one(doc! { "_id": "search_condition" }, None)
+        .await?
+        .and_then(|d| bson_to_i64(d.get("version")))
+        .unwrap_or(0);
+    let max_update_time = get_max_update_time(db).await?;
+    let location_count = db
+        .collection::<Document>("location")
+        .count_documents(None, None)
+        .await?;
+    let store_type_count = db
+        .collection::<Document>("store_type")
+        .count_documents(None, None)
+        .await?;
+    return Ok(format!(
+        "{}-{}-{}-{}",
+        version, max_update_time, location_count, store_type_count
+    ));
+}
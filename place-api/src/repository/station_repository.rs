@@ -0,0 +1,89 @@
+use crate::model::db::station_collection::{StationCollection, StationDistanceCollection};
+use futures::TryStreamExt;
+use mongodb::bson::{doc, from_document, Document};
+use mongodb::options::{FindOptions, ReplaceOptions};
+use mongodb::Database;
+use std::error::Error;
+
+pub async fn get_station(
+    db: &Database,
+    id: String,
+) -> Result<Option<StationCollection>, Box<dyn Error>> {
+    let col = db.collection::<StationCollection>("station");
+    let result = col.find_one(doc! { "_id": id }, None).await?;
+    return Ok(result);
+}
+
+// 正規化した駅名の前方一致
+pub async fn search_stations_by_name(
+    db: &Database,
+    search_name: String,
+    limit: i64,
+) -> Result<Vec<StationCollection>, Box<dyn Error>> {
+    let col = db.collection::<StationCollection>("station");
+    let pattern = format!("^{}", escape_regex(&search_name));
+    let find_options = FindOptions::builder()
+        .sort(doc! { "search_name": 1 })
+        .limit(limit)
+        .build();
+    let results = col
+        .find(doc! { "search_name": { "$regex": pattern } }, find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+fn escape_regex(text: &str) -> String {
+    return text
+        .chars()
+        .map(|c| {
+            if "\\^$.|?*+()[]{}".contains(c) {
+                format!("\\{}", c)
+            } else {
+                c.to_string()
+            }
+        })
+        .collect();
+}
+
+// 指定地点から近い順の駅
+pub async fn get_nearest_stations(
+    db: &Database,
+    lng: f64,
+    lat: f64,
+    max_distance: f64,
+    limit: i64,
+) -> Result<Vec<StationDistanceCollection>, Box<dyn Error>> {
+    let col = db.collection::<Document>("station");
+    let pipeline = vec![
+        doc! {
+            "$geoNear": {
+                "near": { "type": "Point", "coordinates": [lng, lat] },
+                "distanceField": "distance",
+                "maxDistance": max_distance,
+                "spherical": true
+            }
+        },
+        doc! { "$limit": limit },
+    ];
+    let documents: Vec<Document> = col.aggregate(pipeline, None).await?.try_collect().await?;
+    let results = documents
+        .into_iter()
+        .map(from_document::<StationDistanceCollection>)
+        .collect::<Result<Vec<_>, _>>()?;
+    return Ok(results);
+}
+
+// 新規登録の場合はtrue
+pub async fn upsert_station(
+    db: &Database,
+    station: StationCollection,
+) -> Result<bool, Box<dyn Error>> {
+    let col = db.collection::<StationCollection>("station");
+    let options = ReplaceOptions::builder().upsert(true).build();
+    let result = col
+        .replace_one(doc! { "_id": station._id.clone() }, station, options)
+        .await?;
+    return Ok(result.upserted_id.is_some());
+}
@@ -0,0 +1,90 @@
+use crate::model::db::store_favorite_collection::{StoreFavoriteCollection, StoreVisitCollection};
+use futures::TryStreamExt;
+use mongodb::bson::doc;
+use mongodb::options::{FindOptions, UpdateOptions};
+use mongodb::Database;
+use std::error::Error;
+
+pub async fn get_favorite_list(
+    db: &Database,
+    account_id: String,
+) -> Result<Vec<StoreFavoriteCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreFavoriteCollection>("store_favorite");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "create_time": -1 })
+        .build();
+    let results = col
+        .find(doc! { "account_id": account_id }, find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+// 登録済みの場合は何もしない
+pub async fn add_favorite(
+    db: &Database,
+    favorite: StoreFavoriteCollection,
+) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<StoreFavoriteCollection>("store_favorite");
+    let options = UpdateOptions::builder().upsert(true).build();
+    col.update_one(
+        doc! { "account_id": favorite.account_id, "store_id": favorite.store_id },
+        doc! {
+            "$setOnInsert": { "_id": favorite._id, "create_time": favorite.create_time }
+        },
+        options,
+    )
+    .await?;
+    return Ok(());
+}
+
+pub async fn delete_favorite(
+    db: &Database,
+    account_id: String,
+    store_id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreFavoriteCollection>("store_favorite");
+    let result = col
+        .delete_one(
+            doc! { "account_id": account_id, "store_id": store_id },
+            None,
+        )
+        .await?;
+    return Ok(result.deleted_count);
+}
+
+pub async fn get_visit_list(
+    db: &Database,
+    account_id: String,
+    store_id: Option<String>,
+) -> Result<Vec<StoreVisitCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreVisitCollection>("store_visit");
+    let mut query = doc! { "account_id": account_id };
+    if let Some(s) = store_id {
+        query.insert("store_id", s);
+    }
+    let find_options = FindOptions::builder()
+        .sort(doc! { "visit_date": -1, "create_time": -1 })
+        .build();
+    let results = col.find(query, find_options).await?.try_collect().await?;
+    return Ok(results);
+}
+
+pub async fn add_visit(db: &Database, visit: StoreVisitCollection) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<StoreVisitCollection>("store_visit");
+    col.insert_one(visit, None).await?;
+    return Ok(());
+}
+
+pub async fn delete_visit(
+    db: &Database,
+    account_id: String,
+    id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreVisitCollection>("store_visit");
+    let result = col
+        .delete_one(doc! { "_id": id, "account_id": account_id }, None)
+        .await?;
+    return Ok(result.deleted_count);
+}
@@ -0,0 +1,332 @@
+use crate::model::db::store_collection::{
+    StoreClusterCollection, StoreCollection, StoreDistanceCollection,
+};
+use crate::model::db::store_review_collection::StoreRatingCollection;
+use crate::util::geo_util;
+use crate::util::search_text_util;
+use futures::TryStreamExt;
+use mongodb::bson::{doc, from_document, to_document, Bson, Document};
+use mongodb::options::{FindOptions, UpdateOptions};
+use mongodb::Database;
+use std::error::Error;
+
+// 店舗検索の絞り込み条件（空の場合は絞り込まない）
+pub struct StoreSearchFilter {
+    pub store_types: Vec<String>,
+    pub location_ids: Vec<String>,
+    pub keyword_bigrams: Vec<String>,
+    pub store_ids: Vec<String>,
+    // 評価の平均の下限
+    pub min_rating: Option<f64>,
+    // 地域指定時、location_id未設定の店舗を検索する半径（メートル）
+    pub unassigned_location_radius: Option<f64>,
+}
+
+// 更新時は評価の集計を上書きしない
+fn to_update_document(store: &StoreCollection) -> Result<Document, Box<dyn Error>> {
+    let mut document = to_document(store)?;
+    document.remove("_id");
+    document.remove("rating");
+    return Ok(document);
+}
+
+fn to_filter_query(filter: StoreSearchFilter) -> Document {
+    let mut query = doc! {};
+    if !filter.store_types.is_empty() {
+        query.insert("store_type", doc! { "$in": filter.store_types });
+    }
+    if !filter.location_ids.is_empty() {
+        query.insert("location_id", doc! { "$in": filter.location_ids });
+    }
+    if !filter.keyword_bigrams.is_empty() {
+        // 1つでも一致する店舗をインデックスで絞り、一致数が足りない店舗は件数の上限の前に除く
+        let min_match_count = search_text_util::get_min_match_count(filter.keyword_bigrams.len());
+        query.insert(
+            "search_tokens",
+            doc! { "$in": filter.keyword_bigrams.clone() },
+        );
+        query.insert(
+            "$expr",
+            doc! {
+                "$gte": [
+                    {
+                        "$size": {
+                            "$setIntersection": [
+                                { "$ifNull": ["$search_tokens", []] },
+                                filter.keyword_bigrams
+                            ]
+                        }
+                    },
+                    min_match_count as i64
+                ]
+            },
+        );
+    }
+    if !filter.store_ids.is_empty() {
+        query.insert("_id", doc! { "$in": filter.store_ids });
+    }
+    if let Some(r) = filter.min_rating {
+        query.insert("rating.average", doc! { "$gte": r });
+    }
+    return query;
+}
+
+// 指定地点から近い順に店舗を検索
+pub async fn search_stores(
+    db: &Database,
+    lng: f64,
+    lat: f64,
+    radius: Option<f64>,
+    filter: StoreSearchFilter,
+    limit: i64,
+) -> Result<Vec<StoreDistanceCollection>, Box<dyn Error>> {
+    let col = db.collection::<Document>("store");
+    let unassigned_location_radius = filter
+        .unassigned_location_radius
+        .filter(|_| !filter.location_ids.is_empty());
+    let mut query = to_filter_query(filter);
+    if let (Some(r), Some(location_query)) =
+        (unassigned_location_radius, query.remove("location_id"))
+    {
+        query.insert(
+            "$or",
+            vec![
+                doc! { "location_id": location_query },
+                doc! {
+                    "location_id": null,
+                    "loc": {
+                        "$geoWithin": {
+                            "$centerSphere": [[lng, lat], geo_util::get_angular_distance(r)]
+                        }
+                    }
+                },
+            ],
+        );
+    }
+    let mut geo_near = doc! {
+        "near": { "type": "Point", "coordinates": [lng, lat] },
+        "distanceField": "distance",
+        "query": query,
+        "spherical": true
+    };
+    if let Some(r) = radius {
+        geo_near.insert("maxDistance", r);
+    }
+    let pipeline = vec![doc! { "$geoNear": geo_near }, doc! { "$limit": limit }];
+    let documents: Vec<Document> = col.aggregate(pipeline, None).await?.try_collect().await?;
+    let results = documents
+        .into_iter()
+        .map(from_document::<StoreDistanceCollection>)
+        .collect::<Result<Vec<_>, _>>()?;
+    return Ok(results);
+}
+
+pub async fn get_store_list(db: &Database) -> Result<Vec<StoreCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let find_options = FindOptions::builder().sort(doc! { "_id": 1 }).build();
+    let results = col.find(None, find_options).await?.try_collect().await?;
+    return Ok(results);
+}
+
+pub async fn get_store_list_by_filter(
+    db: &Database,
+    filter: StoreSearchFilter,
+) -> Result<Vec<StoreCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let find_options = FindOptions::builder().sort(doc! { "_id": 1 }).build();
+    let results = col
+        .find(to_filter_query(filter), find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+// 範囲内の店舗を格子（cell_size度）ごとに集計
+pub async fn get_store_clusters(
+    db: &Database,
+    bbox: [f64; 4],
+    cell_size: f64,
+    filter: StoreSearchFilter,
+) -> Result<Vec<StoreClusterCollection>, Box<dyn Error>> {
+    let col = db.collection::<Document>("store");
+    let [min_lng, min_lat, max_lng, max_lat] = bbox;
+    let mut query = to_filter_query(filter);
+    // 表示範囲の矩形（経線・緯線に沿った平面の矩形として判定）
+    query.insert(
+        "loc",
+        doc! {
+            "$geoWithin": {
+                "$box": [[min_lng, min_lat], [max_lng, max_lat]]
+            }
+        },
+    );
+    // 格子は表示範囲によらず経度・緯度0を基準に区切る
+    let pipeline = vec![
+        doc! { "$match": query },
+        doc! {
+            "$group": {
+                "_id": {
+                    "x": { "$floor": { "$divide": [{ "$arrayElemAt": ["$loc", 0] }, cell_size] } },
+                    "y": { "$floor": { "$divide": [{ "$arrayElemAt": ["$loc", 1] }, cell_size] } }
+                },
+                "count": { "$sum": 1 },
+                "lng": { "$avg": { "$arrayElemAt": ["$loc", 0] } },
+                "lat": { "$avg": { "$arrayElemAt": ["$loc", 1] } },
+                "store_id": { "$first": "$_id" },
+                "name": { "$first": "$name" },
+                "store_type": { "$first": "$store_type" }
+            }
+        },
+        doc! { "$sort": { "_id.y": 1, "_id.x": 1 } },
+    ];
+    let documents: Vec<Document> = col.aggregate(pipeline, None).await?.try_collect().await?;
+    let results = documents
+        .into_iter()
+        .map(from_document::<StoreClusterCollection>)
+        .collect::<Result<Vec<_>, _>>()?;
+    return Ok(results);
+}
+
+pub async fn get_store(
+    db: &Database,
+    id: String,
+) -> Result<Option<StoreCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let result = col.find_one(doc! { "_id": id }, None).await?;
+    return Ok(result);
+}
+
+pub async fn count_stores_by_store_type(
+    db: &Database,
+    store_type: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let count = col
+        .count_documents(doc! { "store_type": store_type }, None)
+        .await?;
+    return Ok(count);
+}
+
+pub async fn count_stores_by_location_id(
+    db: &Database,
+    location_id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let count = col
+        .count_documents(doc! { "location_id": location_id }, None)
+        .await?;
+    return Ok(count);
+}
+
+pub async fn add_store(db: &Database, store: StoreCollection) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    col.insert_one(store, None).await?;
+    return Ok(());
+}
+
+// 評価の集計以外を更新
+pub async fn update_store(db: &Database, store: StoreCollection) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let result = col
+        .update_one(
+            doc! { "_id": store._id.clone() },
+            doc! { "$set": to_update_document(&store)? },
+            None,
+        )
+        .await?;
+    return Ok(result.matched_count);
+}
+
+// レビューから店舗の評価の集計をDB上で作り直して保存（同時の更新で古い集計が残らないよう1回の集計で反映）
+pub async fn recalculate_store_rating(
+    db: &Database,
+    id: String,
+    min_rating: i32,
+    max_rating: i32,
+) -> Result<Option<StoreRatingCollection>, Box<dyn Error>> {
+    let col = db.collection::<Document>("store");
+    let pipeline = vec![
+        doc! { "$match": { "_id": id.clone() } },
+        doc! {
+            "$lookup": {
+                "from": "store_review",
+                "localField": "_id",
+                "foreignField": "store_id",
+                "as": "reviews"
+            }
+        },
+        doc! {
+            "$project": {
+                "rating": {
+                    // 小数第2位で四捨五入
+                    "average": {
+                        "$cond": [
+                            { "$gt": [{ "$size": "$reviews" }, 0] },
+                            { "$round": [{ "$avg": "$reviews.rating" }, 2] },
+                            0.0
+                        ]
+                    },
+                    "count": { "$toLong": { "$size": "$reviews" } },
+                    // 評価ごとの件数
+                    "distribution": {
+                        "$map": {
+                            "input": { "$range": [min_rating, max_rating + 1] },
+                            "as": "r",
+                            "in": {
+                                "$toLong": {
+                                    "$size": {
+                                        "$filter": {
+                                            "input": "$reviews",
+                                            "cond": { "$eq": ["$$this.rating", "$$r"] }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        doc! {
+            "$merge": {
+                "into": "store",
+                "on": "_id",
+                "whenMatched": "merge",
+                "whenNotMatched": "discard"
+            }
+        },
+    ];
+    col.aggregate(pipeline, None).await?;
+    let result = db
+        .collection::<StoreCollection>("store")
+        .find_one(doc! { "_id": id }, None)
+        .await?
+        .and_then(|s| s.rating);
+    return Ok(result);
+}
+
+pub async fn delete_store(db: &Database, id: String) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreCollection>("store");
+    let result = col.delete_one(doc! { "_id": id }, None).await?;
+    return Ok(result.deleted_count);
+}
+
+// 新規登録の場合はtrue
+// 取り込みで指定されていない項目（null）は既存の値を残す
+pub async fn upsert_store(db: &Database, store: StoreCollection) -> Result<bool, Box<dyn Error>> {
+    let document: Document = to_update_document(&store)?
+        .into_iter()
+        .filter(|(_, v)| *v != Bson::Null)
+        .collect();
+    let col = db.collection::<StoreCollection>("store");
+    let options = UpdateOptions::builder().upsert(true).build();
+    let result = col
+        .update_one(
+            doc! { "_id": store._id.clone() },
+            doc! { "$set": document },
+            options,
+        )
+        .await?;
+    return Ok(result.upserted_id.is_some());
+}
@@ -0,0 +1,80 @@
+use crate::model::db::store_review_collection::StoreReviewCollection;
+use futures::TryStreamExt;
+use mongodb::bson::doc;
+use mongodb::options::{FindOptions, UpdateOptions};
+use mongodb::Database;
+use std::error::Error;
+
+pub async fn get_review_list(
+    db: &Database,
+    store_id: String,
+    offset: u64,
+    limit: i64,
+) -> Result<Vec<StoreReviewCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreReviewCollection>("store_review");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "update_time": -1 })
+        .skip(offset)
+        .limit(limit)
+        .build();
+    let results = col
+        .find(doc! { "store_id": store_id }, find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+pub async fn get_review_list_by_account_id(
+    db: &Database,
+    account_id: String,
+) -> Result<Vec<StoreReviewCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreReviewCollection>("store_review");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "update_time": -1 })
+        .build();
+    let results = col
+        .find(doc! { "account_id": account_id }, find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+// 登録済みの場合は評価と本文を更新
+pub async fn save_review(
+    db: &Database,
+    review: StoreReviewCollection,
+) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<StoreReviewCollection>("store_review");
+    let options = UpdateOptions::builder().upsert(true).build();
+    col.update_one(
+        doc! { "account_id": review.account_id, "store_id": review.store_id },
+        doc! {
+            "$set": {
+                "rating": review.rating,
+                "text": review.text,
+                "update_time": review.update_time
+            },
+            "$setOnInsert": { "_id": review._id, "create_time": review.create_time }
+        },
+        options,
+    )
+    .await?;
+    return Ok(());
+}
+
+pub async fn delete_review(
+    db: &Database,
+    account_id: String,
+    store_id: String,
+) -> Result<u64, Box<dyn Error>> {
+    let col = db.collection::<StoreReviewCollection>("store_review");
+    let result = col
+        .delete_one(
+            doc! { "account_id": account_id, "store_id": store_id },
+            None,
+        )
+        .await?;
+    return Ok(result.deleted_count);
+}
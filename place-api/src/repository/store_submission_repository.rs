@@ -0,0 +1,110 @@
+use crate::model::db::store_submission_collection::{
+    StoreSubmissionCollection, StoreSubmissionStatus,
+};
+use futures::TryStreamExt;
+use mongodb::bson::{doc, to_bson};
+use mongodb::options::FindOptions;
+use mongodb::Database;
+use std::error::Error;
+
+pub async fn get_submission_list_by_status(
+    db: &Database,
+    status: StoreSubmissionStatus,
+) -> Result<Vec<StoreSubmissionCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreSubmissionCollection>("store_submission");
+    // 未対応は古い順、対応済みは新しい順
+    let sort_order = if status == StoreSubmissionStatus::Pending {
+        1
+    } else {
+        -1
+    };
+    let find_options = FindOptions::builder()
+        .sort(doc! { "create_time": sort_order })
+        .build();
+    let results = col
+        .find(doc! { "status": to_bson(&status)? }, find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+pub async fn get_submission_list_by_account_id(
+    db: &Database,
+    account_id: String,
+) -> Result<Vec<StoreSubmissionCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreSubmissionCollection>("store_submission");
+    let find_options = FindOptions::builder()
+        .sort(doc! { "create_time": -1 })
+        .build();
+    let results = col
+        .find(doc! { "account_id": account_id }, find_options)
+        .await?
+        .try_collect()
+        .await?;
+    return Ok(results);
+}
+
+pub async fn get_submission(
+    db: &Database,
+    id: String,
+) -> Result<Option<StoreSubmissionCollection>, Box<dyn Error>> {
+    let col = db.collection::<StoreSubmissionCollection>("store_submission");
+    let result = col.find_one(doc! { "_id": id }, None).await?;
+    return Ok(result);
+}
+
+pub async fn add_submission(
+    db: &Database,
+    submission: StoreSubmissionCollection,
+) -> Result<(), Box<dyn Error>> {
+    let col = db.collection::<StoreSubmissionCollection>("store_submission");
+    col.insert_one(submission, None).await?;
+    return Ok(());
+}
+
+// 未対応の場合のみ承認・却下にする（更新できた場合はtrue）
+pub async fn update_submission_status(
+    db: &Database,
+    id: String,
+    status: StoreSubmissionStatus,
+    reject_reason: Option<String>,
+    review_time: i64,
+) -> Result<bool, Box<dyn Error>> {
+    let col = db.collection::<StoreSubmissionCollection>("store_submission");
+    let result = col
+        .update_one(
+            doc! { "_id": id, "status": to_bson(&StoreSubmissionStatus::Pending)? },
+            doc! {
+                "$set": {
+                    "status": to_bson(&status)?,
+                    "reject_reason": reject_reason,
+                    "review_time": review_time
+                }
+            },
+            None,
+        )
+        .await?;
+    return Ok(result.modified_count > 0);
+}
+
+// 承認済みにした提案を未対応に戻す（店舗への反映に失敗した場合）
+pub async fn revert_submission_to_pending(
+    db: &Database,
+    id: String,
+) -> Result<bool, Box<dyn Error>> {
+    let col = db.collection::<StoreSubmissionCollection>("store_submission");
+    let result = col
+        .update_one(
+            doc! { "_id": id, "status": to_bson(&StoreSubmissionStatus::Approved)? },
+            doc! {
+                "$set": {
+                    "status": to_bson(&StoreSubmissionStatus::Pending)?,
+                    "review_time": null
+                }
+            },
+            None,
+        )
+        .await?;
+    return Ok(result.modified_count > 0);
+}
@@ -0,0 +1,404 @@
+use crate::model::api::admin_request::{
+    LocationRequest, SortOrderRequest, StoreRequest, StoreTypeRequest,
+};
+use crate::model::db::search_condition_collection::{LocationCollection, StoreTypeCollection};
+use crate::model::db::store_collection::StoreCollection;
+use crate::repository::search_condition_repository;
+use crate::repository::store_repository;
+use crate::service::station_service;
+use crate::util::date_util;
+use crate::util::geo_util;
+use crate::util::lang_util;
+use crate::util::validate_util;
+use mongodb::Database;
+use std::collections::HashMap;
+use std::error::Error;
+
+// 並び順の間隔
+const SORT_ORDER_STEP: i32 = 1000;
+// 地域未設定の店舗に地域を割り当てる範囲（地域検索の従来の半径）
+const LOCATION_BACKFILL_RADIUS: f64 = 1000.0;
+
+fn validate_name(name: &str) -> Result<(), Box<dyn Error>> {
+    if name.trim().is_empty() {
+        return Err("Name is required".into());
+    }
+    return Ok(());
+}
+
+// 日本語の名称は必須、その他の言語は任意
+fn validate_names(name: &str, names: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+    validate_name(name)?;
+    lang_util::validate_names(names)?;
+    return Ok(());
+}
+
+fn validate_location_request(request: &LocationRequest) -> Result<(), Box<dyn Error>> {
+    if !validate_util::is_valid_id(&request.id) {
+        return Err("Invalid id".into());
+    }
+    validate_names(&request.name, &request.names)?;
+    if !validate_util::is_valid_loc(&request.loc) {
+        return Err("loc must be [lng, lat]".into());
+    }
+    return Ok(());
+}
+
+// 親の地域が1つ上の階層か
+fn validate_location_parent(
+    request: &LocationRequest,
+    locations: &[LocationCollection],
+) -> Result<(), Box<dyn Error>> {
+    return match (request.level.parent_level(), &request.parent_id) {
+        (None, None) => Ok(()),
+        (None, Some(_)) => Err("Prefecture can not have parent".into()),
+        (Some(_), None) => Err("parent_id is required".into()),
+        (Some(parent_level), Some(parent_id)) => {
+            match locations.iter().find(|l| &l._id == parent_id) {
+                Some(p) if p.level == parent_level => Ok(()),
+                Some(_) => Err("Invalid parent level".into()),
+                None => Err("Parent location not found".into()),
+            }
+        }
+    };
+}
+
+pub async fn validate_store_request(
+    db: &Database,
+    request: &StoreRequest,
+) -> Result<(), Box<dyn Error>> {
+    if request.id.trim().is_empty() {
+        return Err("Invalid id".into());
+    }
+    validate_name(&request.name)?;
+    if !validate_util::is_valid_loc(&request.loc) {
+        return Err("loc must be [lng, lat]".into());
+    }
+    if let Some(opening_hours) = &request.opening_hours {
+        opening_hours.validate()?;
+    }
+    if search_condition_repository::get_store_type(db, request.store_type.clone())
+        .await?
+        .is_none()
+    {
+        return Err("store_type not found".into());
+    }
+    if let Some(location_id) = &request.location_id {
+        if search_condition_repository::get_location(db, location_id.clone())
+            .await?
+            .is_none()
+        {
+            return Err("location_id not found".into());
+        }
+    }
+    return Ok(());
+}
+
+// 末尾に追加する場合の並び順
+fn get_next_sort_order(sort_orders: Vec<i32>) -> i32 {
+    return sort_orders.into_iter().max().unwrap_or(0) + SORT_ORDER_STEP;
+}
+
+fn validate_sort_order_request(
+    request: &SortOrderRequest,
+    ids: Vec<String>,
+) -> Result<(), Box<dyn Error>> {
+    let mut request_ids = request.ids.clone();
+    request_ids.sort();
+    let mut ids = ids;
+    ids.sort();
+    if request_ids != ids {
+        return Err("ids must contain all registered ids".into());
+    }
+    return Ok(());
+}
+
+pub async fn add_location(
+    db: &Database,
+    request: LocationRequest,
+) -> Result<LocationCollection, Box<dyn Error>> {
+    validate_location_request(&request)?;
+    let locations = search_condition_repository::get_location_list(db).await?;
+    if locations.iter().any(|l| l._id == request.id) {
+        return Err("Location already exists".into());
+    }
+    validate_location_parent(&request, &locations)?;
+    let location = LocationCollection {
+        _id: request.id,
+        name: request.name,
+        loc: request.loc,
+        sort_order: get_next_sort_order(locations.iter().map(|l| l.sort_order).collect()),
+        update_time: date_util::get_now_timestamp(),
+        level: request.level,
+        parent_id: request.parent_id,
+        names: request.names,
+    };
+    search_condition_repository::add_location(db, location.clone()).await?;
+    return Ok(location);
+}
+
+pub async fn update_location(
+    db: &Database,
+    request: LocationRequest,
+) -> Result<(), Box<dyn Error>> {
+    validate_location_request(&request)?;
+    let locations = search_condition_repository::get_location_list(db).await?;
+    let location = match locations.iter().find(|l| l._id == request.id) {
+        Some(l) => l,
+        None => return Err("Location not found".into()),
+    };
+    validate_location_parent(&request, &locations)?;
+    // 配下の地域がある場合は階層を変更不可
+    let has_children = locations
+        .iter()
+        .any(|l| l.parent_id.as_deref() == Some(request.id.as_str()));
+    if has_children && location.level != request.level {
+        return Err("Can not change level of location with children".into());
+    }
+    let location = LocationCollection {
+        _id: request.id,
+        name: request.name,
+        loc: request.loc,
+        sort_order: location.sort_order,
+        update_time: date_util::get_now_timestamp(),
+        level: request.level,
+        parent_id: request.parent_id,
+        names: request.names,
+    };
+    search_condition_repository::update_location(db, location).await?;
+    return Ok(());
+}
+
+pub async fn sort_locations(
+    db: &Database,
+    request: SortOrderRequest,
+) -> Result<(), Box<dyn Error>> {
+    let locations = search_condition_repository::get_location_list(db).await?;
+    validate_sort_order_request(&request, locations.into_iter().map(|l| l._id).collect())?;
+    let update_time = date_util::get_now_timestamp();
+    for (i, id) in request.ids.into_iter().enumerate() {
+        search_condition_repository::update_location_sort_order(
+            db,
+            id,
+            (i as i32 + 1) * SORT_ORDER_STEP,
+            update_time,
+        )
+        .await?;
+    }
+    return Ok(());
+}
+
+pub async fn delete_location(db: &Database, id: String) -> Result<(), Box<dyn Error>> {
+    // 配下の地域や店舗がある場合は削除不可
+    let locations = search_condition_repository::get_location_list(db).await?;
+    if locations
+        .iter()
+        .any(|l| l.parent_id.as_deref() == Some(id.as_str()))
+    {
+        return Err("Location has children".into());
+    }
+    if store_repository::count_stores_by_location_id(db, id.clone()).await? > 0 {
+        return Err("Location is in use".into());
+    }
+    let count = search_condition_repository::delete_location(db, id).await?;
+    if count == 0 {
+        return Err("Location not found".into());
+    }
+    return Ok(());
+}
+
+pub async fn add_store_type(
+    db: &Database,
+    request: StoreTypeRequest,
+) -> Result<StoreTypeCollection, Box<dyn Error>> {
+    if !validate_util::is_valid_id(&request.id) {
+        return Err("Invalid id".into());
+    }
+    validate_names(&request.name, &request.names)?;
+    let store_types = search_condition_repository::get_store_type_list(db).await?;
+    if store_types.iter().any(|s| s._id == request.id) {
+        return Err("Store type already exists".into());
+    }
+    let store_type = StoreTypeCollection {
+        _id: request.id,
+        name: request.name,
+        sort_order: get_next_sort_order(store_types.iter().map(|s| s.sort_order).collect()),
+        update_time: date_util::get_now_timestamp(),
+        names: request.names,
+    };
+    search_condition_repository::add_store_type(db, store_type.clone()).await?;
+    return Ok(store_type);
+}
+
+pub async fn update_store_type(
+    db: &Database,
+    request: StoreTypeRequest,
+) -> Result<(), Box<dyn Error>> {
+    validate_names(&request.name, &request.names)?;
+    let count = search_condition_repository::update_store_type(
+        db,
+        request.id,
+        request.name,
+        request.names,
+        date_util::get_now_timestamp(),
+    )
+    .await?;
+    if count == 0 {
+        return Err("Store type not found".into());
+    }
+    return Ok(());
+}
+
+pub async fn sort_store_types(
+    db: &Database,
+    request: SortOrderRequest,
+) -> Result<(), Box<dyn Error>> {
+    let store_types = search_condition_repository::get_store_type_list(db).await?;
+    validate_sort_order_request(&request, store_types.into_iter().map(|s| s._id).collect())?;
+    let update_time = date_util::get_now_timestamp();
+    for (i, id) in request.ids.into_iter().enumerate() {
+        search_condition_repository::update_store_type_sort_order(
+            db,
+            id,
+            (i as i32 + 1) * SORT_ORDER_STEP,
+            update_time,
+        )
+        .await?;
+    }
+    return Ok(());
+}
+
+pub async fn delete_store_type(db: &Database, id: String) -> Result<(), Box<dyn Error>> {
+    // 店舗で使用中の種別は削除不可
+    if store_repository::count_stores_by_store_type(db, id.clone()).await? > 0 {
+        return Err("Store type is in use".into());
+    }
+    let count = search_condition_repository::delete_store_type(db, id).await?;
+    if count == 0 {
+        return Err("Store type not found".into());
+    }
+    return Ok(());
+}
+
+pub async fn get_store_list(db: &Database) -> Result<Vec<StoreCollection>, Box<dyn Error>> {
+    return store_repository::get_store_list(db).await;
+}
+
+// update_account_idは変更を提案したユーザ（管理者の場合はNone）
+pub async fn add_store(
+    db: &Database,
+    request: StoreRequest,
+    update_account_id: Option<String>,
+) -> Result<StoreCollection, Box<dyn Error>> {
+    validate_store_request(db, &request).await?;
+    if store_repository::get_store(db, request.id.clone())
+        .await?
+        .is_some()
+    {
+        return Err("Store already exists".into());
+    }
+    let nearest_stations = station_service::get_nearest_stations(db, &request.loc).await?;
+    let store = StoreCollection {
+        _id: request.id,
+        name: request.name,
+        address: request.address,
+        loc: request.loc,
+        store_type: request.store_type,
+        location_id: request.location_id,
+        hours: request.hours,
+        opening_hours: request.opening_hours,
+        update_time: date_util::get_now_timestamp(),
+        update_account_id,
+        search_tokens: Vec::new(),
+        nearest_stations,
+        rating: None,
+    }
+    .with_search_tokens();
+    store_repository::add_store(db, store.clone()).await?;
+    return Ok(store);
+}
+
+// update_account_idは変更を提案したユーザ（管理者の場合はNone）
+pub async fn update_store(
+    db: &Database,
+    request: StoreRequest,
+    update_account_id: Option<String>,
+) -> Result<StoreCollection, Box<dyn Error>> {
+    validate_store_request(db, &request).await?;
+    let nearest_stations = station_service::get_nearest_stations(db, &request.loc).await?;
+    let store = StoreCollection {
+        _id: request.id,
+        name: request.name,
+        address: request.address,
+        loc: request.loc,
+        store_type: request.store_type,
+        location_id: request.location_id,
+        hours: request.hours,
+        opening_hours: request.opening_hours,
+        update_time: date_util::get_now_timestamp(),
+        update_account_id,
+        search_tokens: Vec::new(),
+        nearest_stations,
+        rating: None,
+    }
+    .with_search_tokens();
+    let count = store_repository::update_store(db, store.clone()).await?;
+    if count == 0 {
+        return Err("Store not found".into());
+    }
+    return Ok(store);
+}
+
+pub async fn delete_store(db: &Database, id: String) -> Result<(), Box<dyn Error>> {
+    let count = store_repository::delete_store(db, id).await?;
+    if count == 0 {
+        return Err("Store not found".into());
+    }
+    return Ok(());
+}
+
+// 登録済み店舗の検索用トークンを再作成
+pub async fn rebuild_store_search_tokens(db: &Database) -> Result<usize, Box<dyn Error>> {
+    let stores = store_repository::get_store_list(db).await?;
+    let count = stores.len();
+    for store in stores {
+        store_repository::update_store(db, store.with_search_tokens()).await?;
+    }
+    return Ok(count);
+}
+
+// 地域未設定の店舗に、範囲内で最も近い末端の地域を割り当てる
+pub async fn backfill_store_location_ids(db: &Database) -> Result<usize, Box<dyn Error>> {
+    let locations = search_condition_repository::get_location_list(db).await?;
+    let leaf_locations: Vec<&LocationCollection> = locations
+        .iter()
+        .filter(|l| l.loc.len() == 2)
+        .filter(|l| {
+            !locations
+                .iter()
+                .any(|c| c.parent_id.as_deref() == Some(l._id.as_str()))
+        })
+        .collect();
+    let stores = store_repository::get_store_list(db).await?;
+    let mut count = 0;
+    for mut store in stores
+        .into_iter()
+        .filter(|s| s.location_id.is_none() && s.loc.len() == 2)
+    {
+        let nearest = leaf_locations
+            .iter()
+            .map(|l| {
+                let distance =
+                    geo_util::get_distance(store.loc[0], store.loc[1], l.loc[0], l.loc[1]);
+                (distance, l)
+            })
+            .filter(|(d, _)| *d <= LOCATION_BACKFILL_RADIUS)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b));
+        if let Some((_, l)) = nearest {
+            store.location_id = Some(l._id.clone());
+            store_repository::update_store(db, store).await?;
+            count += 1;
+        }
+    }
+    return Ok(count);
+}
@@ -0,0 +1,26 @@
+use crate::service::auth::account_user_service;
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::error::ErrorUnauthorized;
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage};
+
+// 認証済みのユーザ（ハンドラーではReqData<AuthAccount>で取得）
+#[derive(Clone, Debug)]
+pub struct AuthAccount {
+    pub account_id: String,
+}
+
+// Authorizationヘッダーのトークンが有効な場合のみ後続の処理を実行
+pub async fn require_auth(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let account_id =
+        match account_user_service::get_account_id_from_authorization_header(req.request()) {
+            Some(id) => id,
+            None => return Err(ErrorUnauthorized("Unauthorized")),
+        };
+    req.extensions_mut().insert(AuthAccount { account_id });
+    return next.call(req).await;
+}
@@ -0,0 +1,44 @@
+use oauth2::basic::BasicClient;
+use oauth2::reqwest::async_http_client;
+use oauth2::{
+    AuthUrl, AuthorizationCode, ClientId, ClientSecret, RedirectUrl, TokenResponse, TokenUrl,
+};
+use std::env;
+use std::error::Error;
+
+// googleの認可コードからgmailを取得
+pub async fn get_gmail_from_google_auth_code(auth_code: String) -> Result<String, Box<dyn Error>> {
+    // oauth用のクライアント
+    let google_client_id = ClientId::new(env::var("GOOGLE_AUTH_CLIENT_ID")?);
+    let google_client_secret = ClientSecret::new(env::var("GOOGLE_AUTH_CLIENT_SECRET")?);
+    let auth_url = AuthUrl::new("https://accounts.google.com/o/oauth2/v2/auth".to_string());
+    let token_url = TokenUrl::new("https://www.googleapis.com/oauth2/v3/token".to_string());
+    let oauth_client = BasicClient::new(
+        google_client_id,
+        Some(google_client_secret),
+        auth_url?,
+        Some(token_url?),
+    )
+    .set_redirect_uri(RedirectUrl::new(env::var("FRONT_DOMAIN")?)?);
+    // 認証コードからトークン取得
+    let token = oauth_client
+        .exchange_code(AuthorizationCode::new(auth_code))
+        .request_async(async_http_client)
+        .await?;
+    let access_token = token.access_token().secret();
+
+    // アクセストークンからユーザ情報を取得
+    let resp = reqwest::Client::new()
+        .get("https://www.googleapis.com/oauth2/v3/userinfo")
+        .bearer_auth(access_token)
+        .send()
+        .await?;
+    let user_info: serde_json::Value = serde_json::from_str(&resp.text().await?)?;
+    return match (
+        user_info["email"].as_str(),
+        user_info["email_verified"].as_bool(),
+    ) {
+        (Some(email), Some(true)) => Ok(email.to_string()),
+        (_, _) => Err("Can not get verified gmail".into()),
+    };
+}
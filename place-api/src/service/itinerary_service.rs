@@ -0,0 +1,229 @@
+use crate::model::api::itinerary_request::ItineraryRequest;
+use crate::model::api::itinerary_response::{ItineraryResponse, ItineraryStopResponse};
+use crate::model::api::store_response::StoreResponse;
+use crate::model::db::store_collection::StoreDistanceCollection;
+use crate::repository::store_repository::{self, StoreSearchFilter};
+use crate::service::search_service;
+use crate::util::date_util;
+use crate::util::geo_util;
+use chrono::{DateTime, Duration};
+use chrono_tz::Tz;
+use mongodb::Database;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::error::Error;
+
+const MAX_STOPS: usize = 8;
+const MAX_TIME_BUDGET_MINUTES: i64 = 24 * 60;
+const DEFAULT_STAY_MINUTES: i64 = 60;
+const MAX_STAY_MINUTES: i64 = 240;
+// 経路の現在地ごとの候補数と候補を探す半径の上限（メートル）
+const CANDIDATE_LIMIT: i64 = 30;
+const MAX_CANDIDATE_RADIUS: f64 = 5000.0;
+// 各段階で残す経路の数
+const BEAM_WIDTH: usize = 50;
+
+// 立ち寄り1件分（店舗と到着・出発日時）
+#[derive(Clone)]
+struct ItineraryStop {
+    store: StoreDistanceCollection,
+    walk_distance: f64,
+    arrival_time: DateTime<Tz>,
+    departure_time: DateTime<Tz>,
+}
+
+#[derive(Clone)]
+struct ItineraryRoute {
+    stops: Vec<ItineraryStop>,
+    lng: f64,
+    lat: f64,
+    time: DateTime<Tz>,
+    total_walk_distance: f64,
+}
+
+// 到着から出発まで1つの営業区間で営業しているか（営業時間が未登録の店舗は営業中として扱う）
+fn is_open_during(
+    s: &StoreDistanceCollection,
+    arrival_time: DateTime<Tz>,
+    departure_time: DateTime<Tz>,
+) -> bool {
+    return match &s.store.opening_hours {
+        Some(o) => o.is_open_during(arrival_time, departure_time),
+        None => true,
+    };
+}
+
+// 経路に次の店舗を追加した経路の一覧（重複・営業時間外・時間超過は除く）
+fn extend_route(
+    route: &ItineraryRoute,
+    candidates: &[StoreDistanceCollection],
+    stay: Duration,
+    end_time: DateTime<Tz>,
+) -> Vec<ItineraryRoute> {
+    let used_ids: Vec<&str> = route
+        .stops
+        .iter()
+        .map(|s| s.store.store._id.as_str())
+        .collect();
+    return candidates
+        .iter()
+        .filter(|c| !used_ids.contains(&c.store._id.as_str()) && c.store.loc.len() == 2)
+        .filter_map(|c| {
+            let walk_distance =
+                geo_util::get_distance(route.lng, route.lat, c.store.loc[0], c.store.loc[1]);
+            let arrival_time =
+                route.time + Duration::minutes(geo_util::get_walk_minutes(walk_distance));
+            let departure_time = arrival_time + stay;
+            if departure_time > end_time || !is_open_during(c, arrival_time, departure_time) {
+                return None;
+            }
+            let mut next = route.clone();
+            next.stops.push(ItineraryStop {
+                store: c.clone(),
+                walk_distance: walk_distance.round(),
+                arrival_time,
+                departure_time,
+            });
+            next.lng = c.store.loc[0];
+            next.lat = c.store.loc[1];
+            next.time = departure_time;
+            next.total_walk_distance += walk_distance;
+            Some(next)
+        })
+        .collect();
+}
+
+// 経路の現在地から残り時間で歩ける範囲（上限あり）の候補を近い順に取得
+async fn get_candidates(
+    db: &Database,
+    store_type: &str,
+    route: &ItineraryRoute,
+    stay: Duration,
+    end_time: DateTime<Tz>,
+) -> Result<Vec<StoreDistanceCollection>, Box<dyn Error>> {
+    let remaining_minutes = (end_time - route.time - stay).num_minutes();
+    if remaining_minutes < 0 {
+        return Ok(Vec::new());
+    }
+    let radius = geo_util::get_walk_distance(remaining_minutes).clamp(1.0, MAX_CANDIDATE_RADIUS);
+    let filter = StoreSearchFilter {
+        store_types: vec![store_type.to_string()],
+        location_ids: Vec::new(),
+        keyword_bigrams: Vec::new(),
+        store_ids: Vec::new(),
+        min_rating: None,
+        unassigned_location_radius: None,
+    };
+    return store_repository::search_stores(
+        db,
+        route.lng,
+        route.lat,
+        Some(radius),
+        filter,
+        CANDIDATE_LIMIT,
+    )
+    .await;
+}
+
+fn validate_request(request: &ItineraryRequest) -> Result<(), Box<dyn Error>> {
+    if !(-180.0..=180.0).contains(&request.lng) || !(-90.0..=90.0).contains(&request.lat) {
+        return Err("Invalid lat/lng".into());
+    }
+    if request.store_types.is_empty() || request.store_types.len() > MAX_STOPS {
+        return Err(format!("store_types must be 1 to {} items", MAX_STOPS).into());
+    }
+    if request.time_budget_minutes <= 0 || request.time_budget_minutes > MAX_TIME_BUDGET_MINUTES {
+        return Err("Invalid time_budget_minutes".into());
+    }
+    if request
+        .stay_minutes
+        .is_some_and(|m| !(0..=MAX_STAY_MINUTES).contains(&m))
+    {
+        return Err("Invalid stay_minutes".into());
+    }
+    return Ok(());
+}
+
+// 店舗種別の順に、徒歩の合計距離が短くなる店舗の組み合わせをビームサーチで探索
+pub async fn plan_itinerary(
+    db: &Database,
+    request: ItineraryRequest,
+    lang: &str,
+) -> Result<ItineraryResponse, Box<dyn Error>> {
+    validate_request(&request)?;
+    let start_time = match &request.start_time {
+        Some(s) => date_util::parse_str_jst_date_time(s)?,
+        None => date_util::get_now_jst_date_time(),
+    };
+    let end_time = start_time + Duration::minutes(request.time_budget_minutes);
+    let stay = Duration::minutes(request.stay_minutes.unwrap_or(DEFAULT_STAY_MINUTES));
+    let mut routes = vec![ItineraryRoute {
+        stops: Vec::new(),
+        lng: request.lng,
+        lat: request.lat,
+        time: start_time,
+        total_walk_distance: 0.0,
+    }];
+    for (i, store_type) in request.store_types.iter().enumerate() {
+        // 候補は経路ごとの現在地の近くから取得（同じ地点の経路は共有）
+        let mut candidates_cache: HashMap<(u64, u64), Vec<StoreDistanceCollection>> =
+            HashMap::new();
+        let mut next_routes: Vec<ItineraryRoute> = Vec::new();
+        for route in &routes {
+            let key = (route.lng.to_bits(), route.lat.to_bits());
+            if let Entry::Vacant(e) = candidates_cache.entry(key) {
+                e.insert(get_candidates(db, store_type, route, stay, end_time).await?);
+            }
+            next_routes.extend(extend_route(route, &candidates_cache[&key], stay, end_time));
+        }
+        // 時間内・営業時間内に立ち寄れる店舗がなければ打ち切り
+        if next_routes.is_empty() {
+            // 出発地点から歩ける範囲に店舗種別の店舗がない場合
+            if i == 0 && candidates_cache.values().all(|c| c.is_empty()) {
+                return Err(format!("No store found for store_type '{}'", store_type).into());
+            }
+            return Err("No itinerary found within time budget".into());
+        }
+        next_routes.sort_by(|a, b| a.total_walk_distance.total_cmp(&b.total_walk_distance));
+        next_routes.truncate(BEAM_WIDTH);
+        routes = next_routes;
+    }
+    let route = match routes.into_iter().next() {
+        Some(r) => r,
+        None => return Err("No itinerary found within time budget".into()),
+    };
+
+    let store_type_names = search_service::get_store_type_names(db, lang).await?;
+    let stops = route
+        .stops
+        .iter()
+        .map(|s| {
+            // 距離は出発地点から
+            let mut candidate = s.store.clone();
+            candidate.distance = geo_util::get_distance(
+                request.lng,
+                request.lat,
+                candidate.store.loc[0],
+                candidate.store.loc[1],
+            );
+            let store_type_name = store_type_names.get(&candidate.store.store_type).cloned();
+            ItineraryStopResponse {
+                store: StoreResponse::from_collection(
+                    candidate,
+                    s.arrival_time,
+                    None,
+                    store_type_name,
+                ),
+                walk_distance: s.walk_distance,
+                walk_minutes: geo_util::get_walk_minutes(s.walk_distance),
+                arrival_time: date_util::format_jst_date_time(s.arrival_time),
+                departure_time: date_util::format_jst_date_time(s.departure_time),
+            }
+        })
+        .collect();
+    return Ok(ItineraryResponse {
+        total_walk_distance: route.total_walk_distance.round(),
+        total_minutes: (route.time - start_time).num_minutes(),
+        stops,
+    });
+}
@@ -0,0 +1,114 @@
+use crate::repository::search_condition_repository;
+use crate::service::search_service;
+use mongodb::Database;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+// DBの検索条件マスタのバージョンを確認する間隔（他インスタンス・マイグレーションでの変更用）
+const VERSION_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Clone)]
+pub struct CachedSearchCondition {
+    // キャッシュ作成時点の検索条件マスタのバージョン
+    pub version: String,
+    pub etag: String,
+    pub body: String,
+}
+
+// 最後にDBで確認したバージョンと確認日時
+struct CheckedVersion {
+    version: String,
+    checked_at: Instant,
+}
+
+// 検索条件マスタの言語ごとのキャッシュ（全ワーカーで共有）
+pub struct SearchConditionCache {
+    cached: RwLock<HashMap<String, CachedSearchCondition>>,
+    checked_version: RwLock<Option<CheckedVersion>>,
+}
+
+impl SearchConditionCache {
+    pub fn new() -> SearchConditionCache {
+        return SearchConditionCache {
+            cached: RwLock::new(HashMap::new()),
+            checked_version: RwLock::new(None),
+        };
+    }
+
+    // 管理APIでマスタを更新した場合に破棄（次のリクエストでバージョンを再確認）
+    pub fn invalidate(&self) {
+        if let Ok(mut cached) = self.cached.write() {
+            cached.clear();
+        }
+        if let Ok(mut checked_version) = self.checked_version.write() {
+            *checked_version = None;
+        }
+    }
+
+    // 確認の間隔内であれば最後に確認したバージョン
+    fn get_checked_version(&self) -> Option<String> {
+        let checked_version = self.checked_version.read().ok()?;
+        return checked_version
+            .as_ref()
+            .filter(|c| c.checked_at.elapsed() < VERSION_CHECK_INTERVAL)
+            .map(|c| c.version.clone());
+    }
+
+    fn set_checked_version(&self, version: String) {
+        if let Ok(mut checked_version) = self.checked_version.write() {
+            *checked_version = Some(CheckedVersion {
+                version,
+                checked_at: Instant::now(),
+            });
+        }
+    }
+
+    fn get(&self, lang: &str, version: &str) -> Option<CachedSearchCondition> {
+        let cached = self.cached.read().ok()?;
+        return cached.get(lang).filter(|c| c.version == version).cloned();
+    }
+
+    fn set(&self, lang: &str, value: CachedSearchCondition) {
+        if let Ok(mut cached) = self.cached.write() {
+            cached.insert(lang.to_string(), value);
+        }
+    }
+}
+
+fn create_etag(body: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    body.hash(&mut hasher);
+    return format!("\"{:x}\"", hasher.finish());
+}
+
+// 検索条件マスタのバージョンが変わっていなければキャッシュを返す（DBのバージョンは一定間隔で確認）
+pub async fn get_search_condition(
+    db: &Database,
+    cache: &SearchConditionCache,
+    lang: &str,
+) -> Result<CachedSearchCondition, Box<dyn Error>> {
+    let version = match cache.get_checked_version() {
+        Some(v) => v,
+        None => {
+            let v = search_condition_repository::get_search_condition_version(db).await?;
+            cache.set_checked_version(v.clone());
+            v
+        }
+    };
+    if let Some(cached) = cache.get(lang, &version) {
+        return Ok(cached);
+    }
+    let response = search_service::get_search_condition_response(db, lang).await?;
+    let body = serde_json::to_string(&response)?;
+    let cached = CachedSearchCondition {
+        version,
+        etag: create_etag(&body),
+        body,
+    };
+    cache.set(lang, cached.clone());
+    return Ok(cached);
+}
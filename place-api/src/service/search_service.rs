@@ -1,26 +1,261 @@
 use crate::model::api::search_condition_response::{
-    SearchConditionResponse, SearchConditionResponseKV,
+    SearchConditionResponse, SearchConditionResponseKV, SearchConditionResponseLocation,
 };
+use crate::model::api::store_response::StoreResponse;
+use crate::model::api::store_search_request::{StoreSearchQuery, StoreSearchSort};
+use crate::model::db::search_condition_collection::{
+    get_descendant_location_ids, LocationCollection,
+};
+use crate::model::db::store_collection::StoreDistanceCollection;
 use crate::repository::search_condition_repository::{get_location_list, get_store_type_list};
+use crate::repository::station_repository;
+use crate::repository::store_repository::{self, StoreSearchFilter};
+use crate::util::date_util;
+use crate::util::search_text_util;
+use mongodb::Database;
+use std::collections::HashMap;
 use std::error::Error;
 
-pub fn get_search_condition_response() -> Result<SearchConditionResponse, Box<dyn Error>> {
-    let location_list = get_location_list()?
+fn to_location_node(
+    locations: &[LocationCollection],
+    location: &LocationCollection,
+    lang: &str,
+) -> SearchConditionResponseLocation {
+    return SearchConditionResponseLocation {
+        key: location._id.clone(),
+        value: location.get_name(lang),
+        level: location.level,
+        children: locations
+            .iter()
+            .filter(|l| l.parent_id.as_deref() == Some(location._id.as_str()))
+            .map(|l| to_location_node(locations, l, lang))
+            .collect(),
+    };
+}
+
+// 地域のツリーを作成（並び順は取得順、親が存在しない地域はルートとして扱う）
+fn build_location_tree(
+    locations: &[LocationCollection],
+    lang: &str,
+) -> Vec<SearchConditionResponseLocation> {
+    return locations
         .iter()
-        .map(|l| SearchConditionResponseKV {
-            key: l._id.clone(),
-            value: l.name.clone(),
+        .filter(|l| match &l.parent_id {
+            Some(p) => !locations.iter().any(|parent| &parent._id == p),
+            None => true,
         })
+        .map(|l| to_location_node(locations, l, lang))
         .collect();
-    let store_type_list = get_store_type_list()?
+}
+
+pub async fn get_search_condition_response(
+    db: &Database,
+    lang: &str,
+) -> Result<SearchConditionResponse, Box<dyn Error>> {
+    let location_list = build_location_tree(&get_location_list(db).await?, lang);
+    let store_type_list = get_store_type_list(db)
+        .await?
         .iter()
         .map(|l| SearchConditionResponseKV {
             key: l._id.clone(),
-            value: l.name.clone(),
+            value: l.get_name(lang),
         })
         .collect();
     return Ok(SearchConditionResponse {
+        lang: lang.to_string(),
         location: location_list,
         store_type: store_type_list,
     });
 }
+
+// 検索半径の初期値と上限（メートル）
+const DEFAULT_RADIUS: f64 = 1000.0;
+const MAX_RADIUS: f64 = 20000.0;
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+// レビューの評価の範囲
+const MIN_RATING: f64 = 1.0;
+const MAX_RATING: f64 = 5.0;
+
+// best_ratedでの評価の重み（残りは距離の重み）
+const BEST_RATED_RATING_WEIGHT: f64 = 0.7;
+// レビューが少ない店舗は評価を全体の平均に近づける
+const RATING_PRIOR_AVERAGE: f64 = 3.0;
+const RATING_PRIOR_COUNT: f64 = 5.0;
+
+// 評価（0〜1）と近さ（0〜1）の加重平均
+fn calc_best_rated_score(s: &StoreDistanceCollection, max_distance: f64) -> f64 {
+    let (average, count) = match &s.store.rating {
+        Some(r) => (r.average, r.count as f64),
+        None => (0.0, 0.0),
+    };
+    let rating = (RATING_PRIOR_AVERAGE * RATING_PRIOR_COUNT + average * count)
+        / (RATING_PRIOR_COUNT + count);
+    let rating_score = (rating - MIN_RATING) / (MAX_RATING - MIN_RATING);
+    let distance_score = 1.0 - (s.distance / max_distance).min(1.0);
+    return rating_score * BEST_RATED_RATING_WEIGHT
+        + distance_score * (1.0 - BEST_RATED_RATING_WEIGHT);
+}
+
+// 店舗種別IDと表示言語での名称
+pub async fn get_store_type_names(
+    db: &Database,
+    lang: &str,
+) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let names = get_store_type_list(db)
+        .await?
+        .into_iter()
+        .map(|t| (t._id.clone(), t.get_name(lang)))
+        .collect();
+    return Ok(names);
+}
+
+// カンマ区切りの店舗種別
+pub fn parse_store_types(store_type: Option<&str>) -> Vec<String> {
+    return store_type
+        .map(|s| {
+            s.split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+}
+
+pub async fn search_stores(
+    db: &Database,
+    query: StoreSearchQuery,
+    lang: &str,
+) -> Result<Vec<StoreResponse>, Box<dyn Error>> {
+    // 緯度経度、駅、地域（配下の地域を含む店舗を地域の座標から近い順）の順に優先
+    let (lng, lat, location_ids) =
+        match (query.lng, query.lat, query.station_key, query.location_key) {
+            (Some(lng), Some(lat), _, _) => (lng, lat, Vec::new()),
+            (_, _, Some(station_key), _) => {
+                match station_repository::get_station(db, station_key).await? {
+                    Some(s) if s.loc.len() == 2 => (s.loc[0], s.loc[1], Vec::new()),
+                    _ => return Err("Station not found".into()),
+                }
+            }
+            (_, _, _, Some(location_key)) => {
+                let locations = get_location_list(db).await?;
+                match locations.iter().find(|l| l._id == location_key) {
+                    Some(l) if l.loc.len() == 2 => (
+                        l.loc[0],
+                        l.loc[1],
+                        get_descendant_location_ids(&locations, &location_key),
+                    ),
+                    _ => return Err("Location not found".into()),
+                }
+            }
+            (_, _, None, None) => {
+                return Err("location_key, station_key or lat/lng is required".into())
+            }
+        };
+    if !(-180.0..=180.0).contains(&lng) || !(-90.0..=90.0).contains(&lat) {
+        return Err("Invalid lat/lng".into());
+    }
+    // 地域指定の場合は半径の指定がなければ距離で絞り込まない
+    let radius = match query.radius {
+        Some(r) => Some(r),
+        None if location_ids.is_empty() => Some(DEFAULT_RADIUS),
+        None => None,
+    };
+    if radius.is_some_and(|r| r <= 0.0 || r > MAX_RADIUS) {
+        return Err("Invalid radius".into());
+    }
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    // 営業中の判定日時（open_atの指定を優先）
+    let open_at = match &query.open_at {
+        Some(s) => Some(date_util::parse_str_jst_date_time(s)?),
+        None if query.open_now => Some(date_util::get_now_jst_date_time()),
+        None => None,
+    };
+    let evaluate_time = open_at.unwrap_or_else(date_util::get_now_jst_date_time);
+    let store_types = parse_store_types(query.store_type.as_deref());
+    let keyword_bigrams = query
+        .keyword
+        .as_deref()
+        .map(search_text_util::get_bigrams)
+        .unwrap_or_default();
+    if query
+        .min_rating
+        .is_some_and(|r| !(MIN_RATING..=MAX_RATING).contains(&r))
+    {
+        return Err("Invalid min_rating".into());
+    }
+    // 営業中・キーワードでの絞り込みや評価順の場合は上限件数まで取得してから並び替える
+    let search_limit = if open_at.is_some()
+        || !keyword_bigrams.is_empty()
+        || query.sort == Some(StoreSearchSort::BestRated)
+    {
+        MAX_LIMIT
+    } else {
+        limit
+    };
+    let mut results: Vec<(Option<f64>, StoreDistanceCollection)> = store_repository::search_stores(
+        db,
+        lng,
+        lat,
+        radius,
+        StoreSearchFilter {
+            store_types,
+            location_ids,
+            keyword_bigrams: keyword_bigrams.iter().cloned().collect(),
+            store_ids: Vec::new(),
+            min_rating: query.min_rating,
+            // 地域未設定の店舗は従来どおり地域の座標からの半径で検索
+            unassigned_location_radius: Some(radius.unwrap_or(DEFAULT_RADIUS)),
+        },
+        search_limit,
+    )
+    .await?
+    .into_iter()
+    .filter(|s| match open_at {
+        Some(t) => s
+            .store
+            .opening_hours
+            .as_ref()
+            .map(|o| o.is_open_at(t))
+            .unwrap_or(false),
+        None => true,
+    })
+    .map(|s| {
+        let relevance = (!keyword_bigrams.is_empty())
+            .then(|| search_text_util::calc_relevance(&keyword_bigrams, &s.store.search_tokens));
+        (relevance, s)
+    })
+    .filter(|(r, _)| {
+        r.map(|r| r >= search_text_util::MIN_KEYWORD_RELEVANCE)
+            .unwrap_or(true)
+    })
+    .collect();
+    match query.sort {
+        // 距離の近い順（$geoNearの結果順）
+        Some(StoreSearchSort::Distance) => {}
+        Some(StoreSearchSort::BestRated) => {
+            // 距離は検索半径（なければ最も遠い店舗）を基準にする
+            let max_distance = radius
+                .unwrap_or_else(|| results.iter().map(|(_, s)| s.distance).fold(1.0, f64::max));
+            results.sort_by(|(_, a), (_, b)| {
+                calc_best_rated_score(b, max_distance)
+                    .total_cmp(&calc_best_rated_score(a, max_distance))
+            });
+        }
+        // 一致度の高い順、同じ場合は距離の近い順
+        None => {
+            results.sort_by(|(a, _), (b, _)| b.unwrap_or(0.0).total_cmp(&a.unwrap_or(0.0)));
+        }
+    }
+    // 店舗種別の表示名
+    let store_type_names = get_store_type_names(db, lang).await?;
+    let results = results
+        .into_iter()
+        .take(limit as usize)
+        .map(|(r, s)| {
+            let store_type_name = store_type_names.get(&s.store.store_type).cloned();
+            StoreResponse::from_collection(s, evaluate_time, r, store_type_name)
+        })
+        .collect();
+    return Ok(results);
+}
@@ -0,0 +1,183 @@
+use crate::model::api::station_import_response::StationImportResponse;
+use crate::model::api::station_request::StationSearchQuery;
+use crate::model::api::station_response::StationResponse;
+use crate::model::api::store_import_response::StoreImportRowResponse;
+use crate::model::db::station_collection::{StationCollection, StoreStationCollection};
+use crate::repository::station_repository;
+use crate::repository::store_repository;
+use crate::util::date_util;
+use crate::util::geo_util;
+use crate::util::search_text_util;
+use crate::util::validate_util;
+use mongodb::Database;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::error::Error;
+
+// 店舗に保持する最寄り駅の件数と距離の上限（メートル）
+const NEAREST_STATION_LIMIT: i64 = 3;
+const NEAREST_STATION_MAX_DISTANCE: f64 = 2000.0;
+const DEFAULT_SEARCH_LIMIT: i64 = 20;
+const MAX_SEARCH_LIMIT: i64 = 100;
+
+// 取り込む1行分の駅
+#[derive(Debug, Deserialize)]
+struct StationImportRow {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    name: String,
+    // 「|」区切りの路線名
+    #[serde(default)]
+    lines: String,
+    lat: Option<f64>,
+    lng: Option<f64>,
+}
+
+fn to_station_collection(
+    row: StationImportRow,
+    update_time: i64,
+) -> Result<StationCollection, String> {
+    if row.id.is_empty() {
+        return Err("id is required".to_string());
+    }
+    if row.name.is_empty() {
+        return Err("name is required".to_string());
+    }
+    let loc = match (row.lng, row.lat) {
+        (Some(lng), Some(lat)) => vec![lng, lat],
+        _ => return Err("lat and lng are required".to_string()),
+    };
+    if !validate_util::is_valid_loc(&loc) {
+        return Err("lat/lng is out of range".to_string());
+    }
+    return Ok(StationCollection {
+        _id: row.id,
+        search_name: search_text_util::normalize_text(&row.name),
+        name: row.name,
+        lines: row
+            .lines
+            .split('|')
+            .map(|l| l.trim().to_string())
+            .filter(|l| !l.is_empty())
+            .collect(),
+        loc,
+        update_time,
+    });
+}
+
+// 指定地点の最寄り駅（近い順）
+pub async fn get_nearest_stations(
+    db: &Database,
+    loc: &[f64],
+) -> Result<Vec<StoreStationCollection>, Box<dyn Error>> {
+    if loc.len() != 2 {
+        return Ok(Vec::new());
+    }
+    let results = station_repository::get_nearest_stations(
+        db,
+        loc[0],
+        loc[1],
+        NEAREST_STATION_MAX_DISTANCE,
+        NEAREST_STATION_LIMIT,
+    )
+    .await?
+    .into_iter()
+    .map(|s| StoreStationCollection {
+        station_id: s.station._id,
+        name: s.station.name,
+        lines: s.station.lines,
+        distance: s.distance.round(),
+        walk_minutes: geo_util::get_walk_minutes(s.distance),
+    })
+    .collect();
+    return Ok(results);
+}
+
+// 登録済み店舗の最寄り駅を再計算
+pub async fn rebuild_store_nearest_stations(db: &Database) -> Result<usize, Box<dyn Error>> {
+    let stores = store_repository::get_store_list(db).await?;
+    let count = stores.len();
+    for mut store in stores {
+        store.nearest_stations = get_nearest_stations(db, &store.loc).await?;
+        store_repository::update_store(db, store).await?;
+    }
+    return Ok(count);
+}
+
+// ヘッダー付きCSV（id,name,lines,lat,lng）から駅を外部IDでupsertし、店舗の最寄り駅を再計算
+pub async fn import_stations(
+    db: &Database,
+    data: &str,
+) -> Result<StationImportResponse, Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data.as_bytes());
+    let update_time = date_util::get_now_timestamp();
+    let mut seen_ids = HashSet::new();
+    let mut response = StationImportResponse {
+        inserted: 0,
+        updated: 0,
+        rejected: 0,
+        stores: 0,
+        rows: Vec::new(),
+    };
+    for (i, row) in reader.deserialize::<StationImportRow>().enumerate() {
+        let station = row.map_err(|e| e.to_string()).and_then(|r| {
+            if !r.id.is_empty() && !seen_ids.insert(r.id.clone()) {
+                return Err(format!("duplicate id '{}' in file", r.id));
+            }
+            to_station_collection(r, update_time)
+        });
+        let row_response = match station {
+            Ok(s) => {
+                let id = s._id.clone();
+                let result = if station_repository::upsert_station(db, s).await? {
+                    response.inserted += 1;
+                    "inserted"
+                } else {
+                    response.updated += 1;
+                    "updated"
+                };
+                StoreImportRowResponse {
+                    row: i + 1,
+                    id: Some(id),
+                    result: result.to_string(),
+                    reason: None,
+                }
+            }
+            Err(reason) => {
+                response.rejected += 1;
+                StoreImportRowResponse {
+                    row: i + 1,
+                    id: None,
+                    result: "rejected".to_string(),
+                    reason: Some(reason),
+                }
+            }
+        };
+        response.rows.push(row_response);
+    }
+    response.stores = rebuild_store_nearest_stations(db).await?;
+    return Ok(response);
+}
+
+pub async fn search_stations(
+    db: &Database,
+    query: StationSearchQuery,
+) -> Result<Vec<StationResponse>, Box<dyn Error>> {
+    let search_name = search_text_util::normalize_text(&query.keyword);
+    if search_name.is_empty() {
+        return Err("keyword is required".into());
+    }
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+    let results = station_repository::search_stations_by_name(db, search_name, limit)
+        .await?
+        .into_iter()
+        .map(StationResponse::from_collection)
+        .collect();
+    return Ok(results);
+}
@@ -0,0 +1,217 @@
+use crate::model::api::store_favorite_request::{
+    FavoriteStoreNearQuery, StoreFavoriteRequest, StoreVisitDeleteRequest, StoreVisitListQuery,
+    StoreVisitRequest,
+};
+use crate::model::api::store_favorite_response::{StoreFavoriteResponse, StoreVisitResponse};
+use crate::model::api::store_response::StoreResponse;
+use crate::model::db::store_collection::StoreCollection;
+use crate::model::db::store_favorite_collection::{StoreFavoriteCollection, StoreVisitCollection};
+use crate::repository::store_favorite_repository;
+use crate::repository::store_repository::{self, StoreSearchFilter};
+use crate::service::search_service;
+use crate::util::date_util;
+use chrono::NaiveDate;
+use mongodb::Database;
+use std::collections::HashMap;
+use std::error::Error;
+use uuid::Uuid;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+const MAX_RADIUS: f64 = 20000.0;
+// 訪問メモの最大文字数
+const MAX_NOTE_LENGTH: usize = 1000;
+
+async fn validate_store_id(db: &Database, store_id: &str) -> Result<(), Box<dyn Error>> {
+    if store_repository::get_store(db, store_id.to_string())
+        .await?
+        .is_none()
+    {
+        return Err("Store not found".into());
+    }
+    return Ok(());
+}
+
+async fn get_stores_by_ids(
+    db: &Database,
+    store_ids: Vec<String>,
+) -> Result<HashMap<String, StoreCollection>, Box<dyn Error>> {
+    if store_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let filter = StoreSearchFilter {
+        store_types: Vec::new(),
+        location_ids: Vec::new(),
+        keyword_bigrams: Vec::new(),
+        store_ids,
+        min_rating: None,
+        unassigned_location_radius: None,
+    };
+    let stores = store_repository::get_store_list_by_filter(db, filter)
+        .await?
+        .into_iter()
+        .map(|s| (s._id.clone(), s))
+        .collect();
+    return Ok(stores);
+}
+
+// お気に入りの新しい順（削除済みの店舗は除く）
+pub async fn get_favorite_list(
+    db: &Database,
+    account_id: String,
+    lang: &str,
+) -> Result<Vec<StoreFavoriteResponse>, Box<dyn Error>> {
+    let favorites = store_favorite_repository::get_favorite_list(db, account_id).await?;
+    let mut stores =
+        get_stores_by_ids(db, favorites.iter().map(|f| f.store_id.clone()).collect()).await?;
+    let store_type_names = search_service::get_store_type_names(db, lang).await?;
+    let results = favorites
+        .into_iter()
+        .filter_map(|f| {
+            let store = stores.remove(&f.store_id)?;
+            let store_type_name = store_type_names.get(&store.store_type).cloned();
+            Some(StoreFavoriteResponse::from_collection(
+                f,
+                store,
+                store_type_name,
+            ))
+        })
+        .collect();
+    return Ok(results);
+}
+
+// 指定地点から近い順のお気に入り店舗
+pub async fn search_favorite_stores(
+    db: &Database,
+    account_id: String,
+    query: FavoriteStoreNearQuery,
+    lang: &str,
+) -> Result<Vec<StoreResponse>, Box<dyn Error>> {
+    if !(-180.0..=180.0).contains(&query.lng) || !(-90.0..=90.0).contains(&query.lat) {
+        return Err("Invalid lat/lng".into());
+    }
+    if query.radius.is_some_and(|r| r <= 0.0 || r > MAX_RADIUS) {
+        return Err("Invalid radius".into());
+    }
+    let store_ids: Vec<String> = store_favorite_repository::get_favorite_list(db, account_id)
+        .await?
+        .into_iter()
+        .map(|f| f.store_id)
+        .collect();
+    if store_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let filter = StoreSearchFilter {
+        store_types: Vec::new(),
+        location_ids: Vec::new(),
+        keyword_bigrams: Vec::new(),
+        store_ids,
+        min_rating: None,
+        unassigned_location_radius: None,
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let evaluate_time = date_util::get_now_jst_date_time();
+    let store_type_names = search_service::get_store_type_names(db, lang).await?;
+    let results =
+        store_repository::search_stores(db, query.lng, query.lat, query.radius, filter, limit)
+            .await?
+            .into_iter()
+            .map(|s| {
+                let store_type_name = store_type_names.get(&s.store.store_type).cloned();
+                StoreResponse::from_collection(s, evaluate_time, None, store_type_name)
+            })
+            .collect();
+    return Ok(results);
+}
+
+pub async fn add_favorite(
+    db: &Database,
+    account_id: String,
+    request: StoreFavoriteRequest,
+) -> Result<(), Box<dyn Error>> {
+    validate_store_id(db, &request.store_id).await?;
+    let favorite = StoreFavoriteCollection {
+        _id: Uuid::new_v4().to_string(),
+        account_id,
+        store_id: request.store_id,
+        create_time: date_util::get_now_timestamp(),
+    };
+    store_favorite_repository::add_favorite(db, favorite).await?;
+    return Ok(());
+}
+
+pub async fn delete_favorite(
+    db: &Database,
+    account_id: String,
+    request: StoreFavoriteRequest,
+) -> Result<(), Box<dyn Error>> {
+    let count =
+        store_favorite_repository::delete_favorite(db, account_id, request.store_id).await?;
+    if count == 0 {
+        return Err("Favorite not found".into());
+    }
+    return Ok(());
+}
+
+// 訪問日の新しい順
+pub async fn get_visit_list(
+    db: &Database,
+    account_id: String,
+    query: StoreVisitListQuery,
+) -> Result<Vec<StoreVisitResponse>, Box<dyn Error>> {
+    let visits = store_favorite_repository::get_visit_list(db, account_id, query.store_id).await?;
+    let stores = get_stores_by_ids(db, visits.iter().map(|v| v.store_id.clone()).collect()).await?;
+    let results = visits
+        .into_iter()
+        .map(|v| {
+            let store_name = stores.get(&v.store_id).map(|s| s.name.clone());
+            StoreVisitResponse::from_collection(v, store_name)
+        })
+        .collect();
+    return Ok(results);
+}
+
+pub async fn add_visit(
+    db: &Database,
+    account_id: String,
+    request: StoreVisitRequest,
+) -> Result<StoreVisitResponse, Box<dyn Error>> {
+    validate_store_id(db, &request.store_id).await?;
+    let visit_date = NaiveDate::parse_from_str(&request.visit_date, "%Y-%m-%d")
+        .map_err(|_| "visit_date must be YYYY-MM-DD")?;
+    let note = request
+        .note
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    if note
+        .as_ref()
+        .is_some_and(|n| n.chars().count() > MAX_NOTE_LENGTH)
+    {
+        return Err("note is too long".into());
+    }
+    let visit = StoreVisitCollection {
+        _id: Uuid::new_v4().to_string(),
+        account_id,
+        store_id: request.store_id,
+        visit_date: visit_date.format("%Y-%m-%d").to_string(),
+        note,
+        create_time: date_util::get_now_timestamp(),
+    };
+    store_favorite_repository::add_visit(db, visit.clone()).await?;
+    let store_name = store_repository::get_store(db, visit.store_id.clone())
+        .await?
+        .map(|s| s.name);
+    return Ok(StoreVisitResponse::from_collection(visit, store_name));
+}
+
+pub async fn delete_visit(
+    db: &Database,
+    account_id: String,
+    request: StoreVisitDeleteRequest,
+) -> Result<(), Box<dyn Error>> {
+    let count = store_favorite_repository::delete_visit(db, account_id, request.id).await?;
+    if count == 0 {
+        return Err("Visit not found".into());
+    }
+    return Ok(());
+}
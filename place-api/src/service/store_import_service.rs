@@ -0,0 +1,234 @@
+use crate::model::api::store_import_response::{StoreImportResponse, StoreImportRowResponse};
+use crate::model::db::opening_hours_collection::OpeningHoursCollection;
+use crate::model::db::store_collection::StoreCollection;
+use crate::repository::search_condition_repository;
+use crate::repository::store_repository;
+use crate::service::station_service;
+use crate::util::date_util;
+use crate::util::validate_util;
+use mongodb::Database;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::error::Error;
+
+// 取り込む1行分の店舗（idは外部ID）
+#[derive(Debug, Default, Deserialize)]
+struct StoreImportRow {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    name: String,
+    address: Option<String>,
+    lat: Option<f64>,
+    lng: Option<f64>,
+    #[serde(default)]
+    store_type: String,
+    location_id: Option<String>,
+    hours: Option<String>,
+    // GeoJSONのみ
+    #[serde(skip)]
+    opening_hours: Option<OpeningHoursCollection>,
+}
+
+// ヘッダー付きCSV（id,name,address,lat,lng,store_type,location_id,hours）
+fn parse_csv(data: &str) -> Result<Vec<Result<StoreImportRow, String>>, Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data.as_bytes());
+    let results = reader
+        .deserialize::<StoreImportRow>()
+        .map(|r| r.map_err(|e| e.to_string()))
+        .collect();
+    return Ok(results);
+}
+
+fn parse_geojson_feature(feature: &Value) -> Result<StoreImportRow, String> {
+    let geometry = &feature["geometry"];
+    if geometry["type"].as_str() != Some("Point") {
+        return Err("geometry must be Point".to_string());
+    }
+    let coordinates = geometry["coordinates"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let properties = &feature["properties"];
+    let get_str = |key: &str| properties[key].as_str().map(|s| s.trim().to_string());
+    let opening_hours = match &properties["opening_hours"] {
+        Value::Null => None,
+        v => Some(
+            serde_json::from_value::<OpeningHoursCollection>(v.clone())
+                .map_err(|e| format!("invalid opening_hours: {}", e))?,
+        ),
+    };
+    // idはfeatureのidを優先
+    let id = match &feature["id"] {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        _ => get_str("id").unwrap_or_default(),
+    };
+    return Ok(StoreImportRow {
+        id,
+        name: get_str("name").unwrap_or_default(),
+        address: get_str("address"),
+        lng: coordinates.first().and_then(|v| v.as_f64()),
+        lat: coordinates.get(1).and_then(|v| v.as_f64()),
+        store_type: get_str("store_type").unwrap_or_default(),
+        location_id: get_str("location_id"),
+        hours: get_str("hours"),
+        opening_hours,
+    });
+}
+
+fn parse_geojson(data: &str) -> Result<Vec<Result<StoreImportRow, String>>, Box<dyn Error>> {
+    let json: Value = serde_json::from_str(data)?;
+    if json["type"].as_str() != Some("FeatureCollection") {
+        return Err("GeoJSON must be FeatureCollection".into());
+    }
+    let results = match json["features"].as_array() {
+        Some(features) => features.iter().map(parse_geojson_feature).collect(),
+        None => Vec::new(),
+    };
+    return Ok(results);
+}
+
+fn to_store_collection(
+    row: StoreImportRow,
+    store_types: &HashSet<String>,
+    location_ids: &HashSet<String>,
+    update_time: i64,
+) -> Result<StoreCollection, String> {
+    if row.id.is_empty() {
+        return Err("id is required".to_string());
+    }
+    if row.name.is_empty() {
+        return Err("name is required".to_string());
+    }
+    let loc = match (row.lng, row.lat) {
+        (Some(lng), Some(lat)) => vec![lng, lat],
+        _ => return Err("lat and lng are required".to_string()),
+    };
+    if !validate_util::is_valid_loc(&loc) {
+        return Err("lat/lng is out of range".to_string());
+    }
+    if !store_types.contains(&row.store_type) {
+        return Err(format!("store_type '{}' not found", row.store_type));
+    }
+    let location_id = row.location_id.filter(|l| !l.is_empty());
+    if let Some(l) = location_id.as_ref().filter(|l| !location_ids.contains(*l)) {
+        return Err(format!("location_id '{}' not found", l));
+    }
+    if let Some(opening_hours) = &row.opening_hours {
+        opening_hours.validate()?;
+    }
+    return Ok(StoreCollection {
+        _id: row.id,
+        name: row.name,
+        address: row.address.filter(|a| !a.is_empty()),
+        loc,
+        store_type: row.store_type,
+        location_id,
+        hours: row.hours.filter(|h| !h.is_empty()),
+        opening_hours: row.opening_hours,
+        update_time,
+        update_account_id: None,
+        search_tokens: Vec::new(),
+        nearest_stations: Vec::new(),
+        rating: None,
+    }
+    .with_search_tokens());
+}
+
+// 新規登録の場合はtrue
+async fn save_import_store(
+    db: &Database,
+    mut store: StoreCollection,
+) -> Result<bool, Box<dyn Error>> {
+    // 住所の指定がない場合は既存の住所で検索用トークンを作成
+    if store.address.is_none() {
+        if let Some(existing) = store_repository::get_store(db, store._id.clone()).await? {
+            store.address = existing.address;
+            store = store.with_search_tokens();
+        }
+    }
+    store.nearest_stations = station_service::get_nearest_stations(db, &store.loc).await?;
+    return store_repository::upsert_store(db, store).await;
+}
+
+// CSVまたはGeoJSONから店舗を外部IDでupsert
+pub async fn import_stores(
+    db: &Database,
+    format: &str,
+    data: &str,
+) -> Result<StoreImportResponse, Box<dyn Error>> {
+    let rows = match format {
+        "csv" => parse_csv(data)?,
+        "geojson" => parse_geojson(data)?,
+        _ => return Err("format must be csv or geojson".into()),
+    };
+    let store_types: HashSet<String> = search_condition_repository::get_store_type_list(db)
+        .await?
+        .into_iter()
+        .map(|s| s._id)
+        .collect();
+    let location_ids: HashSet<String> = search_condition_repository::get_location_list(db)
+        .await?
+        .into_iter()
+        .map(|l| l._id)
+        .collect();
+    let update_time = date_util::get_now_timestamp();
+    let mut seen_ids = HashSet::new();
+    let mut response = StoreImportResponse {
+        inserted: 0,
+        updated: 0,
+        rejected: 0,
+        rows: Vec::new(),
+    };
+    for (i, row) in rows.into_iter().enumerate() {
+        // 取り込めなかった行もidが読めれば返す
+        let row_id = row
+            .as_ref()
+            .ok()
+            .map(|r| r.id.clone())
+            .filter(|id| !id.is_empty());
+        let store = row.and_then(|r| {
+            if !r.id.is_empty() && !seen_ids.insert(r.id.clone()) {
+                return Err(format!("duplicate id '{}' in file", r.id));
+            }
+            to_store_collection(r, &store_types, &location_ids, update_time)
+        });
+        // DBのエラーはその行のみ失敗として扱い、残りの行は続行
+        let result = match store {
+            Ok(s) => save_import_store(db, s).await.map_err(|e| e.to_string()),
+            Err(reason) => Err(reason),
+        };
+        let row_response = match result {
+            Ok(inserted) => {
+                let result = if inserted {
+                    response.inserted += 1;
+                    "inserted"
+                } else {
+                    response.updated += 1;
+                    "updated"
+                };
+                StoreImportRowResponse {
+                    row: i + 1,
+                    id: row_id,
+                    result: result.to_string(),
+                    reason: None,
+                }
+            }
+            Err(reason) => {
+                response.rejected += 1;
+                StoreImportRowResponse {
+                    row: i + 1,
+                    id: row_id,
+                    result: "rejected".to_string(),
+                    reason: Some(reason),
+                }
+            }
+        };
+        response.rows.push(row_response);
+    }
+    return Ok(response);
+}
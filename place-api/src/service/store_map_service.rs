@@ -0,0 +1,127 @@
+use crate::model::api::geojson_response::{GeoJsonFeature, GeoJsonFeatureCollection};
+use crate::model::api::store_map_request::{StoreClusterQuery, StoreExportQuery};
+use crate::model::db::search_condition_collection::get_descendant_location_ids;
+use crate::repository::search_condition_repository::get_location_list;
+use crate::repository::store_repository::{self, StoreSearchFilter};
+use crate::service::search_service;
+use mongodb::Database;
+use serde_json::json;
+use std::error::Error;
+
+// 地図タイル1枚のピクセル数と、集計する格子のピクセル数
+const TILE_SIZE: f64 = 256.0;
+const CLUSTER_CELL_SIZE: f64 = 64.0;
+const MAX_ZOOM: u8 = 22;
+// 集計の負荷を抑えるため表示範囲の経度の幅に上限を設ける
+const MAX_BBOX_SIZE: f64 = 180.0;
+
+// 地域（配下の地域を含む）のID
+async fn get_location_ids(
+    db: &Database,
+    location_key: Option<String>,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let location_key = match location_key.filter(|l| !l.is_empty()) {
+        Some(l) => l,
+        None => return Ok(Vec::new()),
+    };
+    let locations = get_location_list(db).await?;
+    if !locations.iter().any(|l| l._id == location_key) {
+        return Err("Location not found".into());
+    }
+    return Ok(get_descendant_location_ids(&locations, &location_key));
+}
+
+fn validate_bbox(query: &StoreClusterQuery) -> Result<[f64; 4], Box<dyn Error>> {
+    let bbox = [query.min_lng, query.min_lat, query.max_lng, query.max_lat];
+    if !(-180.0..=180.0).contains(&query.min_lng)
+        || !(-180.0..=180.0).contains(&query.max_lng)
+        || !(-90.0..=90.0).contains(&query.min_lat)
+        || !(-90.0..=90.0).contains(&query.max_lat)
+    {
+        return Err("Invalid bbox".into());
+    }
+    if query.min_lng >= query.max_lng || query.min_lat >= query.max_lat {
+        return Err("min must be less than max".into());
+    }
+    if query.max_lng - query.min_lng > MAX_BBOX_SIZE {
+        return Err("bbox is too large".into());
+    }
+    return Ok(bbox);
+}
+
+// 表示範囲の店舗を格子ごとにまとめたGeoJSON（1件の格子は店舗として返す）
+pub async fn get_store_clusters(
+    db: &Database,
+    query: StoreClusterQuery,
+    lang: &str,
+) -> Result<GeoJsonFeatureCollection, Box<dyn Error>> {
+    let bbox = validate_bbox(&query)?;
+    if query.zoom > MAX_ZOOM {
+        return Err("Invalid zoom".into());
+    }
+    // ズームレベルでの格子1辺の度数
+    let cell_size = 360.0 / (TILE_SIZE * 2f64.powi(query.zoom as i32)) * CLUSTER_CELL_SIZE;
+    let filter = StoreSearchFilter {
+        store_types: search_service::parse_store_types(query.store_type.as_deref()),
+        location_ids: get_location_ids(db, query.location_key).await?,
+        keyword_bigrams: Vec::new(),
+        store_ids: Vec::new(),
+        min_rating: None,
+        unassigned_location_radius: None,
+    };
+    let store_type_names = search_service::get_store_type_names(db, lang).await?;
+    let features = store_repository::get_store_clusters(db, bbox, cell_size, filter)
+        .await?
+        .into_iter()
+        .map(|c| {
+            if c.count == 1 {
+                let properties = json!({
+                    "cluster": false,
+                    "name": c.name,
+                    "store_type": c.store_type,
+                    "store_type_name": store_type_names.get(&c.store_type),
+                });
+                GeoJsonFeature::point(Some(c.store_id), c.lng, c.lat, properties)
+            } else {
+                let properties = json!({
+                    "cluster": true,
+                    "point_count": c.count,
+                });
+                GeoJsonFeature::point(None, c.lng, c.lat, properties)
+            }
+        })
+        .collect();
+    return Ok(GeoJsonFeatureCollection::new(features));
+}
+
+// 店舗のGeoJSON（propertiesは取り込みと同じ形式）
+pub async fn export_stores(
+    db: &Database,
+    query: StoreExportQuery,
+) -> Result<GeoJsonFeatureCollection, Box<dyn Error>> {
+    let filter = StoreSearchFilter {
+        store_types: search_service::parse_store_types(query.store_type.as_deref()),
+        location_ids: get_location_ids(db, query.location_key).await?,
+        keyword_bigrams: Vec::new(),
+        store_ids: Vec::new(),
+        min_rating: None,
+        unassigned_location_radius: None,
+    };
+    let features = store_repository::get_store_list_by_filter(db, filter)
+        .await?
+        .into_iter()
+        .filter(|s| s.loc.len() == 2)
+        .map(|s| {
+            let properties = json!({
+                "name": s.name,
+                "address": s.address,
+                "store_type": s.store_type,
+                "location_id": s.location_id,
+                "hours": s.hours,
+                "opening_hours": s.opening_hours,
+            });
+            GeoJsonFeature::point(Some(s._id), s.loc[0], s.loc[1], properties)
+        })
+        .collect();
+    return Ok(GeoJsonFeatureCollection::new(features));
+}
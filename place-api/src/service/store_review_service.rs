@@ -0,0 +1,123 @@
+use crate::model::api::store_review_request::{
+    StoreReviewDeleteRequest, StoreReviewListQuery, StoreReviewRequest,
+};
+use crate::model::api::store_review_response::{StoreReviewListResponse, StoreReviewResponse};
+use crate::model::db::store_review_collection::{StoreRatingCollection, StoreReviewCollection};
+use crate::repository::store_repository;
+use crate::repository::store_review_repository;
+use crate::util::date_util;
+use mongodb::Database;
+use std::error::Error;
+use uuid::Uuid;
+
+const MIN_RATING: i32 = 1;
+const MAX_RATING: i32 = 5;
+const MAX_TEXT_LENGTH: usize = 2000;
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
+// レビューから店舗の評価の集計を作り直して保存
+async fn update_store_rating(
+    db: &Database,
+    store_id: String,
+) -> Result<StoreRatingCollection, Box<dyn Error>> {
+    return match store_repository::recalculate_store_rating(db, store_id, MIN_RATING, MAX_RATING)
+        .await?
+    {
+        Some(r) => Ok(r),
+        None => Err("Store not found".into()),
+    };
+}
+
+pub async fn get_review_list(
+    db: &Database,
+    query: StoreReviewListQuery,
+) -> Result<StoreReviewListResponse, Box<dyn Error>> {
+    let store = match store_repository::get_store(db, query.store_id.clone()).await? {
+        Some(s) => s,
+        None => return Err("Store not found".into()),
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let reviews = store_review_repository::get_review_list(
+        db,
+        query.store_id,
+        query.offset.unwrap_or(0),
+        limit,
+    )
+    .await?
+    .into_iter()
+    .map(StoreReviewResponse::from_collection)
+    .collect();
+    return Ok(StoreReviewListResponse {
+        rating: store.rating.unwrap_or_else(|| StoreRatingCollection {
+            distribution: vec![0; (MAX_RATING - MIN_RATING + 1) as usize],
+            ..Default::default()
+        }),
+        reviews,
+    });
+}
+
+// ログインユーザのレビューの新しい順
+pub async fn get_my_review_list(
+    db: &Database,
+    account_id: String,
+) -> Result<Vec<StoreReviewResponse>, Box<dyn Error>> {
+    let results = store_review_repository::get_review_list_by_account_id(db, account_id)
+        .await?
+        .into_iter()
+        .map(StoreReviewResponse::from_collection)
+        .collect();
+    return Ok(results);
+}
+
+// 1ユーザ1店舗につき1件（登録済みの場合は編集）
+pub async fn save_review(
+    db: &Database,
+    account_id: String,
+    request: StoreReviewRequest,
+) -> Result<StoreRatingCollection, Box<dyn Error>> {
+    if !(MIN_RATING..=MAX_RATING).contains(&request.rating) {
+        return Err("rating must be 1 to 5".into());
+    }
+    let text = request
+        .text
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty());
+    if text
+        .as_ref()
+        .is_some_and(|t| t.chars().count() > MAX_TEXT_LENGTH)
+    {
+        return Err("text is too long".into());
+    }
+    if store_repository::get_store(db, request.store_id.clone())
+        .await?
+        .is_none()
+    {
+        return Err("Store not found".into());
+    }
+    let now = date_util::get_now_timestamp();
+    let review = StoreReviewCollection {
+        _id: Uuid::new_v4().to_string(),
+        account_id,
+        store_id: request.store_id.clone(),
+        rating: request.rating,
+        text,
+        create_time: now,
+        update_time: now,
+    };
+    store_review_repository::save_review(db, review).await?;
+    return update_store_rating(db, request.store_id).await;
+}
+
+pub async fn delete_review(
+    db: &Database,
+    account_id: String,
+    request: StoreReviewDeleteRequest,
+) -> Result<StoreRatingCollection, Box<dyn Error>> {
+    let count =
+        store_review_repository::delete_review(db, account_id, request.store_id.clone()).await?;
+    if count == 0 {
+        return Err("Review not found".into());
+    }
+    return update_store_rating(db, request.store_id).await;
+}
@@ -0,0 +1,244 @@
+use crate::model::api::admin_request::StoreRequest;
+use crate::model::api::store_submission_request::{
+    StoreSubmissionApproveRequest, StoreSubmissionListQuery, StoreSubmissionRejectRequest,
+    StoreSubmissionRequest,
+};
+use crate::model::api::store_submission_response::StoreSubmissionResponse;
+use crate::model::db::store_collection::StoreCollection;
+use crate::model::db::store_submission_collection::{
+    StoreSubmissionCollection, StoreSubmissionDiffCollection, StoreSubmissionFieldsCollection,
+    StoreSubmissionStatus,
+};
+use crate::repository::store_repository;
+use crate::repository::store_submission_repository;
+use crate::service::admin_service;
+use crate::util::date_util;
+use mongodb::bson::{to_document, Bson, Document};
+use mongodb::Database;
+use std::error::Error;
+use uuid::Uuid;
+
+const MAX_REASON_LENGTH: usize = 1000;
+
+fn to_fields(request: StoreSubmissionRequest) -> StoreSubmissionFieldsCollection {
+    let trim = |s: Option<String>| s.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+    return StoreSubmissionFieldsCollection {
+        name: trim(request.name),
+        address: trim(request.address),
+        loc: request.loc,
+        store_type: trim(request.store_type),
+        location_id: trim(request.location_id),
+        hours: trim(request.hours),
+        opening_hours: request.opening_hours,
+    };
+}
+
+// 現在の店舗に提案された項目を反映した内容
+fn to_store_request(
+    id: String,
+    fields: &StoreSubmissionFieldsCollection,
+    current: Option<&StoreCollection>,
+) -> Result<StoreRequest, Box<dyn Error>> {
+    let fields = fields.clone();
+    return Ok(StoreRequest {
+        id,
+        name: fields
+            .name
+            .or_else(|| current.map(|s| s.name.clone()))
+            .ok_or("name is required")?,
+        address: fields
+            .address
+            .or_else(|| current.and_then(|s| s.address.clone())),
+        loc: fields
+            .loc
+            .or_else(|| current.map(|s| s.loc.clone()))
+            .ok_or("loc is required")?,
+        store_type: fields
+            .store_type
+            .or_else(|| current.map(|s| s.store_type.clone()))
+            .ok_or("store_type is required")?,
+        location_id: fields
+            .location_id
+            .or_else(|| current.and_then(|s| s.location_id.clone())),
+        hours: fields
+            .hours
+            .or_else(|| current.and_then(|s| s.hours.clone())),
+        opening_hours: fields
+            .opening_hours
+            .or_else(|| current.and_then(|s| s.opening_hours.clone())),
+    });
+}
+
+// 提案された項目のうち現在の店舗と異なるもの
+fn get_diff(
+    fields: &StoreSubmissionFieldsCollection,
+    current: Option<&StoreCollection>,
+) -> Result<Vec<StoreSubmissionDiffCollection>, Box<dyn Error>> {
+    let current = match current {
+        Some(s) => to_document(s)?,
+        None => Document::new(),
+    };
+    let results = to_document(fields)?
+        .into_iter()
+        .filter(|(_, proposed)| *proposed != Bson::Null)
+        .map(|(field, proposed)| StoreSubmissionDiffCollection {
+            current: current.get(&field).cloned().unwrap_or(Bson::Null),
+            field,
+            proposed,
+        })
+        .filter(|d| d.current != d.proposed)
+        .collect();
+    return Ok(results);
+}
+
+// 新規店舗のID（同じ提案を二重に承認しても重複しない）
+fn get_new_store_id(submission_id: &str) -> String {
+    return format!("submission_{}", submission_id);
+}
+
+pub async fn add_submission(
+    db: &Database,
+    account_id: String,
+    request: StoreSubmissionRequest,
+) -> Result<StoreSubmissionResponse, Box<dyn Error>> {
+    let id = Uuid::new_v4().to_string();
+    let store_id = request.store_id.clone().filter(|s| !s.is_empty());
+    let fields = to_fields(request);
+    let current = match &store_id {
+        Some(s) => match store_repository::get_store(db, s.clone()).await? {
+            Some(store) => Some(store),
+            None => return Err("Store not found".into()),
+        },
+        None => None,
+    };
+    let diff = get_diff(&fields, current.as_ref())?;
+    if diff.is_empty() {
+        return Err("No changes".into());
+    }
+    // 反映後の内容が店舗として登録できるか
+    let store_request = to_store_request(
+        store_id.clone().unwrap_or_else(|| get_new_store_id(&id)),
+        &fields,
+        current.as_ref(),
+    )?;
+    admin_service::validate_store_request(db, &store_request).await?;
+    let submission = StoreSubmissionCollection {
+        _id: id,
+        account_id,
+        store_id,
+        fields,
+        diff,
+        status: StoreSubmissionStatus::Pending,
+        reject_reason: None,
+        create_time: date_util::get_now_timestamp(),
+        review_time: None,
+    };
+    store_submission_repository::add_submission(db, submission.clone()).await?;
+    return Ok(StoreSubmissionResponse::from_collection(submission));
+}
+
+pub async fn get_my_submission_list(
+    db: &Database,
+    account_id: String,
+) -> Result<Vec<StoreSubmissionResponse>, Box<dyn Error>> {
+    let results = store_submission_repository::get_submission_list_by_account_id(db, account_id)
+        .await?
+        .into_iter()
+        .map(StoreSubmissionResponse::from_collection)
+        .collect();
+    return Ok(results);
+}
+
+pub async fn get_submission_list(
+    db: &Database,
+    query: StoreSubmissionListQuery,
+) -> Result<Vec<StoreSubmissionResponse>, Box<dyn Error>> {
+    let status = query.status.unwrap_or(StoreSubmissionStatus::Pending);
+    let results = store_submission_repository::get_submission_list_by_status(db, status)
+        .await?
+        .into_iter()
+        .map(StoreSubmissionResponse::from_collection)
+        .collect();
+    return Ok(results);
+}
+
+// 提案を店舗に反映し、提案したユーザを店舗の更新者として記録
+pub async fn approve_submission(
+    db: &Database,
+    request: StoreSubmissionApproveRequest,
+) -> Result<StoreCollection, Box<dyn Error>> {
+    let submission = match store_submission_repository::get_submission(db, request.id).await? {
+        Some(s) if s.status == StoreSubmissionStatus::Pending => s,
+        Some(_) => return Err("Submission already reviewed".into()),
+        None => return Err("Submission not found".into()),
+    };
+    // 同時の承認・却下で二重に反映しないよう、先に未対応から承認済みに更新できた場合のみ反映
+    let claimed = store_submission_repository::update_submission_status(
+        db,
+        submission._id.clone(),
+        StoreSubmissionStatus::Approved,
+        None,
+        date_util::get_now_timestamp(),
+    )
+    .await?;
+    if !claimed {
+        return Err("Submission already reviewed".into());
+    }
+    return match apply_submission(db, &submission).await {
+        Ok(store) => Ok(store),
+        Err(e) => {
+            // 反映に失敗した場合は未対応に戻す
+            store_submission_repository::revert_submission_to_pending(db, submission._id).await?;
+            Err(e)
+        }
+    };
+}
+
+async fn apply_submission(
+    db: &Database,
+    submission: &StoreSubmissionCollection,
+) -> Result<StoreCollection, Box<dyn Error>> {
+    let store = match &submission.store_id {
+        Some(store_id) => {
+            let current = match store_repository::get_store(db, store_id.clone()).await? {
+                Some(s) => s,
+                None => return Err("Store not found".into()),
+            };
+            let store_request =
+                to_store_request(store_id.clone(), &submission.fields, Some(&current))?;
+            admin_service::update_store(db, store_request, Some(submission.account_id.clone()))
+                .await?
+        }
+        None => {
+            let store_request =
+                to_store_request(get_new_store_id(&submission._id), &submission.fields, None)?;
+            admin_service::add_store(db, store_request, Some(submission.account_id.clone())).await?
+        }
+    };
+    return Ok(store);
+}
+
+pub async fn reject_submission(
+    db: &Database,
+    request: StoreSubmissionRejectRequest,
+) -> Result<(), Box<dyn Error>> {
+    let reason = request.reason.trim().to_string();
+    if reason.is_empty() {
+        return Err("reason is required".into());
+    }
+    if reason.chars().count() > MAX_REASON_LENGTH {
+        return Err("reason is too long".into());
+    }
+    let updated = store_submission_repository::update_submission_status(
+        db,
+        request.id,
+        StoreSubmissionStatus::Rejected,
+        Some(reason),
+        date_util::get_now_timestamp(),
+    )
+    .await?;
+    if !updated {
+        return Err("Submission not found or already reviewed".into());
+    }
+    return Ok(());
+}
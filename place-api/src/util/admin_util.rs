@@ -0,0 +1,16 @@
+use actix_web::HttpRequest;
+use std::env;
+
+pub const ADMIN_KEY_HEADER: &str = "X-Admin-Key";
+
+// 管理者用のキーがヘッダーに指定されているか
+pub fn is_admin_request(req: &HttpRequest) -> bool {
+    let admin_key = match env::var("ADMIN_API_KEY") {
+        Ok(k) if !k.is_empty() => k,
+        _ => return false,
+    };
+    return match req.headers().get(ADMIN_KEY_HEADER) {
+        Some(v) => v.to_str().map(|k| k == admin_key).unwrap_or(false),
+        None => false,
+    };
+}
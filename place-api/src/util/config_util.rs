@@ -0,0 +1,28 @@
+use std::env;
+use std::error::Error;
+
+// 環境変数から読み込む設定
+#[derive(Clone, Debug)]
+pub struct AppConfig {
+    pub bind_address: String,
+    pub port: u16,
+    pub db_connection: String,
+    pub db_name: String,
+    pub db_max_pool_size: u32,
+    pub front_domain: String,
+}
+
+fn get_env_or(key: &str, default_value: &str) -> String {
+    return env::var(key).unwrap_or_else(|_| default_value.to_string());
+}
+
+pub fn load_config() -> Result<AppConfig, Box<dyn Error>> {
+    return Ok(AppConfig {
+        bind_address: get_env_or("BIND_ADDRESS", "0.0.0.0"),
+        port: get_env_or("PORT", "8080").parse()?,
+        db_connection: env::var("DB_CONNECTION").map_err(|_| "DB_CONNECTION is not set")?,
+        db_name: env::var("DB_NAME").map_err(|_| "DB_NAME is not set")?,
+        db_max_pool_size: get_env_or("DB_MAX_POOL_SIZE", "10").parse()?,
+        front_domain: env::var("FRONT_DOMAIN").map_err(|_| "FRONT_DOMAIN is not set")?,
+    });
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::{Asia::Tokyo, Tz};
+use std::error::Error;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 現在日時のUNIX時間（秒）
+pub fn get_now_timestamp() -> i64 {
+    return SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+}
+
+// 日本時間の現在日時
+pub fn get_now_jst_date_time() -> DateTime<Tz> {
+    return Tokyo.from_utc_datetime(&Utc::now().naive_utc());
+}
+
+// 日時の文字列を日本時間にパース（タイムゾーン無しは日本時間として扱う）
+pub fn parse_str_jst_date_time(str_date_time: &str) -> Result<DateTime<Tz>, Box<dyn Error>> {
+    let trimmed = str_date_time.trim();
+    if let Ok(d) = DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(d.with_timezone(&Tokyo));
+    }
+    for format_str in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"] {
+        if let Ok(d) = NaiveDateTime::parse_from_str(trimmed, format_str) {
+            if let Some(jst) = Tokyo.from_local_datetime(&d).single() {
+                return Ok(jst);
+            }
+        }
+    }
+    return Err("Invalid date time".into());
+}
+
+// 日本時間を文字列にフォーマット
+pub fn format_jst_date_time(jst_date_time: DateTime<Tz>) -> String {
+    return jst_date_time.format("%Y-%m-%dT%H:%M:%S%:z").to_string();
+}
@@ -0,0 +1,28 @@
+// 地球の半径（メートル）
+const EARTH_RADIUS: f64 = 6371008.8;
+// 徒歩1分あたりの距離（不動産の表示規約の80m）
+const WALK_METERS_PER_MINUTE: f64 = 80.0;
+
+// 2地点間の大円距離（メートル）
+pub fn get_distance(lng1: f64, lat1: f64, lng2: f64, lat2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lng = (lng2 - lng1).to_radians();
+    let a = (d_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lng / 2.0).sin().powi(2);
+    return 2.0 * EARTH_RADIUS * a.sqrt().asin();
+}
+
+// 距離を中心角（ラジアン、$centerSphere用）に変換
+pub fn get_angular_distance(distance: f64) -> f64 {
+    return distance / EARTH_RADIUS;
+}
+
+// 徒歩の所要時間（1分未満は切り上げ）
+pub fn get_walk_minutes(distance: f64) -> i64 {
+    return (distance / WALK_METERS_PER_MINUTE).ceil().max(1.0) as i64;
+}
+
+// 指定時間で歩ける距離（メートル）
+pub fn get_walk_distance(minutes: i64) -> f64 {
+    return minutes as f64 * WALK_METERS_PER_MINUTE;
+}
@@ -0,0 +1,70 @@
+use actix_web::http::header;
+use actix_web::HttpRequest;
+use std::collections::HashMap;
+
+// 既定の言語（nameは常にこの言語）
+pub const DEFAULT_LANG: &str = "ja";
+// 対応する言語（追加する場合はここに追加）
+pub const SUPPORTED_LANGS: [&str; 2] = ["ja", "en"];
+
+fn to_supported_lang(tag: &str) -> Option<&'static str> {
+    // en-USなどは主言語のみで判定
+    let primary = tag.split(['-', '_']).next()?.trim().to_lowercase();
+    return SUPPORTED_LANGS.iter().find(|l| **l == primary).copied();
+}
+
+// Accept-Languageの言語をq値の高い順に並べる
+fn parse_accept_language(value: &str) -> Vec<String> {
+    let mut tags: Vec<(f64, String)> = value
+        .split(',')
+        .filter_map(|part| {
+            let mut items = part.split(';');
+            let tag = items.next()?.trim().to_string();
+            let q = items
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f64>().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && q > 0.0).then_some((q, tag))
+        })
+        .collect();
+    tags.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+    return tags.into_iter().map(|(_, tag)| tag).collect();
+}
+
+// langパラメータを優先し、なければAccept-Languageから対応言語を選ぶ
+pub fn get_request_lang(req: &HttpRequest, lang: Option<&str>) -> String {
+    if let Some(l) = lang.and_then(to_supported_lang) {
+        return l.to_string();
+    }
+    let accept_language = req
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    return parse_accept_language(accept_language)
+        .iter()
+        .find_map(|tag| to_supported_lang(tag))
+        .unwrap_or(DEFAULT_LANG)
+        .to_string();
+}
+
+// 翻訳がなければ既定の言語の名称
+pub fn get_localized_name(name: &str, names: &HashMap<String, String>, lang: &str) -> String {
+    return names
+        .get(lang)
+        .filter(|n| !n.trim().is_empty())
+        .cloned()
+        .unwrap_or_else(|| name.to_string());
+}
+
+pub fn validate_names(names: &HashMap<String, String>) -> Result<(), String> {
+    for (lang, name) in names {
+        if !SUPPORTED_LANGS.contains(&lang.as_str()) {
+            return Err(format!("Unsupported lang '{}'", lang));
+        }
+        if name.trim().is_empty() {
+            return Err(format!("Name for '{}' is empty", lang));
+        }
+    }
+    return Ok(());
+}
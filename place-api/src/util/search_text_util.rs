@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+// キーワード検索で対象にするbigramの一致割合の下限
+pub const MIN_KEYWORD_RELEVANCE: f64 = 0.5;
+
+// 半角カナと対応する全角カナ
+const HALF_WIDTH_KANA: &str = "ｦｧｨｩｪｫｬｭｮｯｰｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝ";
+const FULL_WIDTH_KANA: &str = "ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン";
+// 濁点を付けられるカナ
+const VOICED_KANA: &str = "カキクケコサシスセソタチツテトハヒフヘホ";
+// 店名・住所で表記揺れのある異体字
+const KANJI_VARIANTS: [(char, char); 10] = [
+    ('髙', '高'),
+    ('﨑', '崎'),
+    ('嵜', '崎'),
+    ('邊', '辺'),
+    ('邉', '辺'),
+    ('齋', '斉'),
+    ('齊', '斉'),
+    ('濵', '浜'),
+    ('櫻', '桜'),
+    ('ヶ', 'が'),
+];
+
+// 半角カナを全角に変換（濁点・半濁点は結合）
+fn to_full_width_kana(text: &str) -> Vec<char> {
+    let half: Vec<char> = HALF_WIDTH_KANA.chars().collect();
+    let full: Vec<char> = FULL_WIDTH_KANA.chars().collect();
+    let mut results: Vec<char> = Vec::new();
+    for c in text.chars() {
+        match (c, results.last().copied()) {
+            ('ﾞ', Some('ウ')) => *results.last_mut().unwrap() = 'ヴ',
+            ('ﾞ', Some(p)) if VOICED_KANA.contains(p) => {
+                *results.last_mut().unwrap() = char::from_u32(p as u32 + 1).unwrap_or(p)
+            }
+            ('ﾟ', Some(p)) if "ハヒフヘホ".contains(p) => {
+                *results.last_mut().unwrap() = char::from_u32(p as u32 + 2).unwrap_or(p)
+            }
+            _ => match half.iter().position(|h| *h == c) {
+                Some(i) => results.push(full[i]),
+                None => results.push(c),
+            },
+        }
+    }
+    return results;
+}
+
+// 全角英数を半角小文字、カタカナをひらがなに揃え、記号・空白を除く
+pub fn normalize_text(text: &str) -> String {
+    return to_full_width_kana(text)
+        .into_iter()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            'ァ'..='ヶ' if c != 'ヵ' && c != 'ヶ' => {
+                char::from_u32(c as u32 - 0x60).unwrap_or(c)
+            }
+            _ => KANJI_VARIANTS
+                .iter()
+                .find(|(v, _)| *v == c)
+                .map(|(_, n)| *n)
+                .unwrap_or(c),
+        })
+        .filter(|c| c.is_alphanumeric() || *c == 'ー')
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+}
+
+// 文字bigram（1文字の場合はその文字）
+pub fn get_bigrams(text: &str) -> HashSet<String> {
+    let chars: Vec<char> = normalize_text(text).chars().collect();
+    if chars.len() < 2 {
+        return chars.iter().map(|c| c.to_string()).collect();
+    }
+    return chars.windows(2).map(|w| w.iter().collect()).collect();
+}
+
+// 文字単位のトークン（1文字のキーワード用）
+fn get_unigrams(text: &str) -> HashSet<String> {
+    return normalize_text(text)
+        .chars()
+        .map(|c| c.to_string())
+        .collect();
+}
+
+// 店名・住所の検索用トークン（bigramと1文字のキーワード用のunigram）
+pub fn get_store_search_tokens(name: &str, address: Option<&str>) -> Vec<String> {
+    let texts = [Some(name), address];
+    let mut tokens: Vec<String> = texts
+        .iter()
+        .flatten()
+        .flat_map(|t| get_bigrams(t).into_iter().chain(get_unigrams(t)))
+        .collect();
+    tokens.sort();
+    tokens.dedup();
+    return tokens;
+}
+
+// 一致割合の下限を満たすのに必要な一致数
+pub fn get_min_match_count(keyword_bigram_count: usize) -> usize {
+    return ((keyword_bigram_count as f64 * MIN_KEYWORD_RELEVANCE).ceil() as usize).max(1);
+}
+
+// キーワードのbigramのうち店舗のトークンに含まれる割合
+pub fn calc_relevance(keyword_bigrams: &HashSet<String>, search_tokens: &[String]) -> f64 {
+    if keyword_bigrams.is_empty() {
+        return 0.0;
+    }
+    let matched = keyword_bigrams
+        .iter()
+        .filter(|b| search_tokens.contains(b))
+        .count();
+    return matched as f64 / keyword_bigrams.len() as f64;
+}
@@ -0,0 +1,15 @@
+// マスタのIDは英小文字・数字・アンダースコアのみ
+pub fn is_valid_id(id: &str) -> bool {
+    return !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+}
+
+// [経度, 緯度]の形式か
+pub fn is_valid_loc(loc: &[f64]) -> bool {
+    return match loc {
+        [lng, lat] => (-180.0..=180.0).contains(lng) && (-90.0..=90.0).contains(lat),
+        _ => false,
+    };
+}